@@ -0,0 +1,265 @@
+//! Weather alert (NWS/OWM) domain types and the audio/display behavior
+//! built around them: tone selection and repeat scheduling live in
+//! [`tone`]; severity, a raw alert's fields, and their rendering live
+//! here and in sibling modules as they're added.
+
+pub mod nws;
+pub mod quiet_hours;
+pub mod severity;
+pub mod silence;
+pub mod timing;
+pub mod tone;
+
+pub use severity::SeverityFilter;
+pub use silence::{AlertSilence, SilenceMode};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertKind {
+    Advisory,
+    Watch,
+    Warning,
+}
+
+impl AlertKind {
+    /// Parses the kind NWS/console commands spell out as a plain word
+    /// (case-insensitive), for the `testalert` console command and any
+    /// future alert-source parser.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "advisory" => Some(AlertKind::Advisory),
+            "watch" => Some(AlertKind::Watch),
+            "warning" => Some(AlertKind::Warning),
+            _ => None,
+        }
+    }
+
+    /// Higher is more severe; used to sort `active_alerts` most-severe
+    /// first and to gate [`quiet_hours::is_quiet`] by a severity floor.
+    pub fn severity_rank(self) -> u8 {
+        match self {
+            AlertKind::Advisory => 0,
+            AlertKind::Watch => 1,
+            AlertKind::Warning => 2,
+        }
+    }
+
+    /// NWS doesn't send a kind field directly — it's the last word of the
+    /// `event` string (e.g. "Tornado Warning", "Flood Watch", "Winter
+    /// Weather Advisory"). Used by [`nws::parse_active_alerts`].
+    pub fn from_nws_event(event: &str) -> Option<Self> {
+        let event = event.trim();
+        if event.ends_with("Warning") {
+            Some(AlertKind::Warning)
+        } else if event.ends_with("Watch") {
+            Some(AlertKind::Watch)
+        } else if event.ends_with("Advisory") {
+            Some(AlertKind::Advisory)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub headline: String,
+    pub description: String,
+    pub expires_at_ms: u64,
+    /// When the alert takes effect, if the source provided it. `None`
+    /// means "effective now" as far as we know.
+    pub effective_at_ms: Option<u64>,
+    /// The hazard's own onset time, which can differ from `effective_at_ms`
+    /// (an alert can be issued/effective well before conditions actually
+    /// begin). `None` if the source didn't provide one.
+    pub onset_at_ms: Option<u64>,
+}
+
+/// User-configurable audio behavior for alert tones, persisted as part of
+/// [`crate::settings::SettingsBlob`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AlertAudioSettings {
+    /// How many times to play the tone before giving up, if not silenced
+    /// or cleared first.
+    pub tone_repeat: u32,
+    /// Gap between repeats, in milliseconds.
+    pub tone_gap_ms: u64,
+    /// Whether the all-clear chime plays when alerts go away. Some users
+    /// find the positive chime as intrusive as the warning tone it's
+    /// meant to contrast with, so it's independently suppressible.
+    pub all_clear_enabled: bool,
+    /// How a manual silence interacts with an alert that's still active
+    /// on the next poll (see [`SilenceMode`]).
+    pub silence_mode: SilenceMode,
+    /// Duration a [`SilenceMode::Snooze`] mute lasts before the tone
+    /// resumes, in milliseconds. Unused for the other modes.
+    pub snooze_duration_ms: u64,
+}
+
+impl Default for AlertAudioSettings {
+    fn default() -> Self {
+        Self {
+            tone_repeat: 3,
+            tone_gap_ms: 2_000,
+            all_clear_enabled: true,
+            silence_mode: SilenceMode::Latch,
+            snooze_duration_ms: 15 * 60 * 1_000,
+        }
+    }
+}
+
+/// Settings controlling how an alert's description is rendered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertDisplaySettings {
+    /// Off by default: most descriptions read fine unstyled, and picking
+    /// out the wrong keyword (a false "highlight everything") is worse
+    /// than no highlighting at all.
+    pub keyword_highlight_enabled: bool,
+    pub highlight_keywords: Vec<String>,
+}
+
+impl Default for AlertDisplaySettings {
+    fn default() -> Self {
+        Self {
+            keyword_highlight_enabled: false,
+            highlight_keywords: vec!["TORNADO".to_string(), "EVACUATE".to_string()],
+        }
+    }
+}
+
+/// How long a synthetic alert injected via the `testalert` console command
+/// stays active before it would naturally expire.
+const SYNTHETIC_ALERT_DURATION_MS: u64 = 60 * 60 * 1_000;
+
+/// Builds a fully-formed [`Alert`] for the `testalert` console command:
+/// effective and onset now, expiring an hour out, with a placeholder
+/// description since no real source text exists for a synthetic alert.
+pub fn build_synthetic(kind: AlertKind, headline: &str, now_ms: u64) -> Alert {
+    Alert {
+        kind,
+        headline: headline.to_string(),
+        description: format!("Synthetic {kind:?} alert injected for testing via the console."),
+        expires_at_ms: now_ms + SYNTHETIC_ALERT_DURATION_MS,
+        effective_at_ms: Some(now_ms),
+        onset_at_ms: Some(now_ms),
+    }
+}
+
+/// Tracks whether alerts were active as of the last check, to detect the
+/// exact tick where the alert list goes from non-empty to empty (the
+/// moment the all-clear chime should play), and which headline was on top
+/// (the moment the attention-pulse animation should (re)start).
+#[derive(Debug, Clone, Default)]
+pub struct AlertLifecycle {
+    had_alerts: bool,
+    top_headline: Option<String>,
+}
+
+impl AlertLifecycle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call once per poll with whether any alerts are currently active.
+    /// Returns `true` exactly on the transition from having alerts to
+    /// having none.
+    pub fn update(&mut self, has_alerts: bool) -> bool {
+        let cleared = self.had_alerts && !has_alerts;
+        self.had_alerts = has_alerts;
+        cleared
+    }
+
+    /// Call once per poll with the headline currently on top of
+    /// `active_alerts` (`None` if there isn't one). Returns `true` exactly
+    /// when it differs from the last call's headline, i.e. a genuinely new
+    /// alert has taken the top spot rather than the same one persisting —
+    /// the signal [`crate::display::views::warnings`] uses to (re)start its
+    /// attention pulse.
+    pub fn raised(&mut self, top_headline: Option<&str>) -> bool {
+        let raised = top_headline.is_some() && top_headline != self.top_headline.as_deref();
+        self.top_headline = top_headline.map(str::to_string);
+        raised
+    }
+}
+
+#[cfg(test)]
+mod kind_tests {
+    use super::*;
+
+    #[test]
+    fn from_str_is_case_insensitive() {
+        assert_eq!(AlertKind::from_str("Warning"), Some(AlertKind::Warning));
+        assert_eq!(AlertKind::from_str("WATCH"), Some(AlertKind::Watch));
+        assert_eq!(AlertKind::from_str("advisory"), Some(AlertKind::Advisory));
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_words() {
+        assert_eq!(AlertKind::from_str("tornado"), None);
+    }
+
+    #[test]
+    fn from_nws_event_reads_the_trailing_word() {
+        assert_eq!(AlertKind::from_nws_event("Tornado Warning"), Some(AlertKind::Warning));
+        assert_eq!(AlertKind::from_nws_event("Flood Watch"), Some(AlertKind::Watch));
+        assert_eq!(
+            AlertKind::from_nws_event("Winter Weather Advisory"),
+            Some(AlertKind::Advisory)
+        );
+        assert_eq!(AlertKind::from_nws_event("Special Statement"), None);
+    }
+
+    #[test]
+    fn severity_rank_orders_warning_above_watch_above_advisory() {
+        assert!(AlertKind::Warning.severity_rank() > AlertKind::Watch.severity_rank());
+        assert!(AlertKind::Watch.severity_rank() > AlertKind::Advisory.severity_rank());
+    }
+
+    #[test]
+    fn build_synthetic_fills_in_a_plausible_alert() {
+        let alert = build_synthetic(AlertKind::Warning, "Test Tornado Warning", 10_000);
+        assert_eq!(alert.kind, AlertKind::Warning);
+        assert_eq!(alert.headline, "Test Tornado Warning");
+        assert_eq!(alert.effective_at_ms, Some(10_000));
+        assert_eq!(alert.onset_at_ms, Some(10_000));
+        assert!(alert.expires_at_ms > 10_000);
+    }
+}
+
+#[cfg(test)]
+mod lifecycle_tests {
+    use super::*;
+
+    #[test]
+    fn clearing_from_alerts_to_none_fires_once() {
+        let mut lifecycle = AlertLifecycle::new();
+        assert!(!lifecycle.update(true));
+        assert!(lifecycle.update(false));
+        assert!(!lifecycle.update(false));
+    }
+
+    #[test]
+    fn staying_clear_never_fires() {
+        let mut lifecycle = AlertLifecycle::new();
+        assert!(!lifecycle.update(false));
+        assert!(!lifecycle.update(false));
+    }
+
+    #[test]
+    fn a_new_headline_taking_the_top_spot_fires_once() {
+        let mut lifecycle = AlertLifecycle::new();
+        assert!(lifecycle.raised(Some("Tornado Warning")));
+        assert!(!lifecycle.raised(Some("Tornado Warning")));
+        assert!(lifecycle.raised(Some("Flood Watch")));
+    }
+
+    #[test]
+    fn no_alert_never_fires() {
+        let mut lifecycle = AlertLifecycle::new();
+        assert!(!lifecycle.raised(None));
+        assert!(lifecycle.raised(Some("Tornado Warning")));
+        assert!(!lifecycle.raised(None));
+    }
+}
@@ -0,0 +1,214 @@
+//! Parses NWS `api.weather.gov/alerts/active` GeoJSON into the same
+//! [`Alert`]/[`AlertKind`] types an OWM-derived poller would produce, so
+//! the warnings view, audio, and quiet-hours logic don't care which
+//! source an alert came from. OWM stays the default source; this is
+//! selected per [`crate::config::AlertSource`].
+
+use super::{Alert, AlertKind};
+
+pub const NWS_ALERTS_URL: &str = "https://api.weather.gov/alerts/active";
+
+/// api.weather.gov asks every client to identify itself with a contact,
+/// rather than accepting a generic HTTP-library User-Agent; merged in via
+/// [`crate::net::http_client::HttpClientConfig`].
+pub const NWS_USER_AGENT: &str = "weather-touch-screen-app, contact@example.com";
+
+/// Rejects a response body larger than this before it's handed to serde.
+/// A nationwide active-alerts feed is far bigger than a single OWM
+/// current-conditions response (hundreds of features, each carrying a
+/// GeoJSON geometry), so this is generously larger than
+/// [`crate::weather::Weather`]'s equivalent cap.
+const MAX_BODY_BYTES: usize = 256 * 1_024;
+
+/// Rejects JSON nested deeper than this. Each feature's `geometry` can be
+/// a `MultiPolygon` — `coordinates` nests rings of rings of `[lon, lat]`
+/// pairs several levels deep on top of the `FeatureCollection` ->
+/// `features` -> feature wrapping — so this allows more headroom than the
+/// flatter OWM response shape.
+const MAX_JSON_DEPTH: u32 = 48;
+
+/// See [`crate::json_guard::sanity_check_json`]; bounds are NWS-sized.
+fn sanity_check_json(body: &str) -> anyhow::Result<()> {
+    crate::json_guard::sanity_check_json(body, MAX_BODY_BYTES, MAX_JSON_DEPTH)
+}
+
+/// Parses the `features` array of an NWS alert GeoJSON `FeatureCollection`
+/// into `Alert`s. A feature whose `event` doesn't map to a known
+/// [`AlertKind`] (see [`AlertKind::from_nws_event`]), or that's missing an
+/// `expires` timestamp, is skipped rather than failing the whole parse —
+/// the feed regularly mixes in statement types we don't render.
+pub fn parse_active_alerts(body: &str) -> anyhow::Result<Vec<Alert>> {
+    sanity_check_json(body)?;
+    let v: serde_json::Value = serde_json::from_str(body)?;
+    let features = v["features"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("NWS response has no \"features\" array"))?;
+
+    let mut alerts = Vec::with_capacity(features.len());
+    for feature in features {
+        let props = &feature["properties"];
+        let event = props["event"].as_str().unwrap_or("");
+        let Some(kind) = AlertKind::from_nws_event(event) else {
+            continue;
+        };
+        let Some(expires_at_ms) = props["expires"].as_str().and_then(parse_timestamp_ms) else {
+            continue;
+        };
+
+        alerts.push(Alert {
+            kind,
+            headline: props["headline"].as_str().unwrap_or(event).to_string(),
+            description: props["description"].as_str().unwrap_or("").to_string(),
+            expires_at_ms,
+            effective_at_ms: props["effective"].as_str().and_then(parse_timestamp_ms),
+            onset_at_ms: props["onset"].as_str().and_then(parse_timestamp_ms),
+        });
+    }
+    Ok(alerts)
+}
+
+/// Parses an ISO-8601 timestamp (`YYYY-MM-DDTHH:MM:SS` plus `Z` or a
+/// `±HH:MM` offset, the shape NWS always sends) to milliseconds since the
+/// Unix epoch, without pulling in a date/time crate. Returns `None` for
+/// anything that doesn't match.
+fn parse_timestamp_ms(s: &str) -> Option<u64> {
+    if s.len() < 19 {
+        return None;
+    }
+    let year: i64 = s.get(0..4)?.parse().ok()?;
+    let month: i64 = s.get(5..7)?.parse().ok()?;
+    let day: i64 = s.get(8..10)?.parse().ok()?;
+    let hour: i64 = s.get(11..13)?.parse().ok()?;
+    let minute: i64 = s.get(14..16)?.parse().ok()?;
+    let second: i64 = s.get(17..19)?.parse().ok()?;
+
+    let offset_s: i64 = match s.get(19..20) {
+        None | Some("Z") => 0,
+        Some(sign @ ("+" | "-")) => {
+            let off_h: i64 = s.get(20..22)?.parse().ok()?;
+            let off_m: i64 = s.get(23..25)?.parse().ok()?;
+            let magnitude = off_h * 3_600 + off_m * 60;
+            if sign == "-" {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+        Some(_) => return None,
+    };
+
+    let days = days_since_epoch(year, month, day)?;
+    let secs = days * 86_400 + hour * 3_600 + minute * 60 + second - offset_s;
+    if secs < 0 {
+        return None;
+    }
+    Some(secs as u64 * 1_000)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Days between 1970-01-01 and the given date (proleptic Gregorian, no
+/// leap seconds — matches how NWS timestamps are issued).
+fn days_since_epoch(year: i64, month: i64, day: i64) -> Option<i64> {
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    const DAYS_IN_MONTH: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+    let mut days = 0i64;
+    if year >= 1970 {
+        for y in 1970..year {
+            days += if is_leap_year(y) { 366 } else { 365 };
+        }
+    } else {
+        for y in year..1970 {
+            days -= if is_leap_year(y) { 366 } else { 365 };
+        }
+    }
+    for m in 1..month {
+        days += DAYS_IN_MONTH[(m - 1) as usize];
+        if m == 2 && is_leap_year(year) {
+            days += 1;
+        }
+    }
+    days += day - 1;
+    Some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_utc_timestamp() {
+        assert_eq!(parse_timestamp_ms("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_timestamp_ms("2024-06-01T18:30:00Z"), Some(1_717_266_600_000));
+    }
+
+    #[test]
+    fn parses_a_timestamp_with_a_negative_offset() {
+        assert_eq!(
+            parse_timestamp_ms("2024-06-01T13:30:00-05:00"),
+            Some(1_717_266_600_000)
+        );
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert_eq!(parse_timestamp_ms("not a timestamp"), None);
+    }
+
+    const SAMPLE_FEATURE_COLLECTION: &str = r#"{
+        "features": [
+            {
+                "properties": {
+                    "event": "Flood Watch",
+                    "headline": "Flood Watch issued for the river valley",
+                    "description": "Rising water levels expected.",
+                    "effective": "2024-06-01T12:00:00Z",
+                    "onset": "2024-06-01T18:00:00Z",
+                    "expires": "2024-06-02T00:00:00Z"
+                }
+            },
+            {
+                "properties": {
+                    "event": "Special Weather Statement",
+                    "headline": "Not a watch/warning/advisory",
+                    "description": "Should be skipped.",
+                    "expires": "2024-06-02T00:00:00Z"
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parses_a_trimmed_nws_alert_feature() {
+        let alerts = parse_active_alerts(SAMPLE_FEATURE_COLLECTION).unwrap();
+        assert_eq!(alerts.len(), 1);
+        let alert = &alerts[0];
+        assert_eq!(alert.kind, AlertKind::Watch);
+        assert_eq!(alert.headline, "Flood Watch issued for the river valley");
+        assert_eq!(alert.effective_at_ms, Some(1_717_243_200_000));
+        assert_eq!(alert.onset_at_ms, Some(1_717_264_800_000));
+        assert_eq!(alert.expires_at_ms, 1_717_286_400_000);
+    }
+
+    #[test]
+    fn an_oversized_body_is_rejected_before_serde_sees_it() {
+        let body = format!(r#"{{"features":"{}"}}"#, "x".repeat(MAX_BODY_BYTES));
+        assert!(parse_active_alerts(&body).is_err());
+    }
+
+    #[test]
+    fn a_pathologically_nested_geometry_is_rejected() {
+        let extra_depth = MAX_JSON_DEPTH as usize + 1;
+        let body = format!(
+            r#"{{"features":{}{}}}"#,
+            "[".repeat(extra_depth),
+            "]".repeat(extra_depth)
+        );
+        assert!(parse_active_alerts(&body).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! Quiet hours: suppress audible alert tones overnight (or any configured
+//! window) while still showing alerts visually, unless severity is at or
+//! above the configured floor (e.g. warnings still sound through quiet
+//! hours even if advisories don't).
+
+use super::AlertKind;
+use serde::{Deserialize, Serialize};
+
+/// A wall-clock window, in minutes since local midnight, during which
+/// advisory/watch tones are suppressed. `start_minute > end_minute` is
+/// valid and means the window crosses midnight (e.g. 22:00-07:00).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub enabled: bool,
+    pub start_minute: u32,
+    pub end_minute: u32,
+    /// The lowest [`AlertKind`] that still sounds during quiet hours.
+    /// Anything strictly below this is suppressed.
+    pub severity_floor: AlertKind,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+            severity_floor: AlertKind::Warning,
+        }
+    }
+}
+
+fn minute_of_day(now_minute: u32) -> u32 {
+    now_minute % (24 * 60)
+}
+
+fn in_window(now_minute: u32, start_minute: u32, end_minute: u32) -> bool {
+    let now_minute = minute_of_day(now_minute);
+    let start_minute = minute_of_day(start_minute);
+    let end_minute = minute_of_day(end_minute);
+    if start_minute == end_minute {
+        return false;
+    }
+    if start_minute < end_minute {
+        now_minute >= start_minute && now_minute < end_minute
+    } else {
+        // Crosses midnight: "in window" means after start OR before end.
+        now_minute >= start_minute || now_minute < end_minute
+    }
+}
+
+/// Whether a tone for `kind` should be suppressed at `now_minute` (minutes
+/// since local midnight, e.g. from the NTP-synced wall clock).
+pub fn is_quiet(now_minute: u32, kind: AlertKind, settings: &QuietHours) -> bool {
+    settings.enabled
+        && kind.severity_rank() < settings.severity_floor.severity_rank()
+        && in_window(now_minute, settings.start_minute, settings.end_minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(start: u32, end: u32, floor: AlertKind) -> QuietHours {
+        QuietHours {
+            enabled: true,
+            start_minute: start,
+            end_minute: end,
+            severity_floor: floor,
+        }
+    }
+
+    #[test]
+    fn disabled_quiet_hours_never_suppress() {
+        let mut settings = settings(22 * 60, 7 * 60, AlertKind::Warning);
+        settings.enabled = false;
+        assert!(!is_quiet(23 * 60, AlertKind::Advisory, &settings));
+    }
+
+    #[test]
+    fn in_range_window_suppresses_below_floor() {
+        let settings = settings(8 * 60, 20 * 60, AlertKind::Warning);
+        assert!(is_quiet(12 * 60, AlertKind::Advisory, &settings));
+    }
+
+    #[test]
+    fn out_of_range_window_does_not_suppress() {
+        let settings = settings(8 * 60, 20 * 60, AlertKind::Warning);
+        assert!(!is_quiet(21 * 60, AlertKind::Advisory, &settings));
+    }
+
+    #[test]
+    fn severity_at_or_above_floor_still_sounds() {
+        let settings = settings(22 * 60, 7 * 60, AlertKind::Warning);
+        assert!(!is_quiet(23 * 60, AlertKind::Warning, &settings));
+    }
+
+    #[test]
+    fn window_crossing_midnight_covers_both_sides() {
+        let settings = settings(22 * 60, 7 * 60, AlertKind::Warning);
+        assert!(is_quiet(23 * 60, AlertKind::Advisory, &settings));
+        assert!(is_quiet(3 * 60, AlertKind::Advisory, &settings));
+        assert!(!is_quiet(12 * 60, AlertKind::Advisory, &settings));
+    }
+}
@@ -0,0 +1,55 @@
+//! Minimum severity an alert must meet to switch the display to the
+//! warning view or sound a tone at all. Unlike [`super::quiet_hours`],
+//! which only raises the floor overnight, this floor always applies —
+//! advisories are noise for some users regardless of time of day. An
+//! alert below the floor still counts toward [`crate::state::AppState::active_alerts`]
+//! and shows as a status-bar badge (see [`crate::display::status_bar`]),
+//! it just doesn't take over the screen or sound a tone.
+
+use super::AlertKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SeverityFilter {
+    /// The lowest [`AlertKind`] that's allowed to raise the page/tone.
+    /// Anything strictly below this only shows as a status-bar badge.
+    pub min_severity: AlertKind,
+}
+
+impl Default for SeverityFilter {
+    fn default() -> Self {
+        Self {
+            min_severity: AlertKind::Advisory,
+        }
+    }
+}
+
+impl SeverityFilter {
+    /// Whether `kind` is at or above the configured floor.
+    pub fn passes(&self, kind: AlertKind) -> bool {
+        kind.severity_rank() >= self.min_severity.severity_rank()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_default_floor_lets_everything_through() {
+        let filter = SeverityFilter::default();
+        assert!(filter.passes(AlertKind::Advisory));
+        assert!(filter.passes(AlertKind::Watch));
+        assert!(filter.passes(AlertKind::Warning));
+    }
+
+    #[test]
+    fn an_advisory_is_suppressed_while_a_warning_passes_under_watch_and_above() {
+        let filter = SeverityFilter {
+            min_severity: AlertKind::Watch,
+        };
+        assert!(!filter.passes(AlertKind::Advisory));
+        assert!(filter.passes(AlertKind::Watch));
+        assert!(filter.passes(AlertKind::Warning));
+    }
+}
@@ -0,0 +1,125 @@
+//! How a manual "silence" request interacts with an alert that's still
+//! active on the next poll: stays silent until the alert clears (latch),
+//! gets forgotten after one poll (momentary), or pauses for a fixed
+//! duration (snooze). The main loop's alert handling reads
+//! [`AlertSilence::should_sound`] once per poll instead of branching on
+//! these three behaviors inline.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SilenceMode {
+    /// A mute lasts until the alert list clears (see [`AlertSilence::clear`]).
+    Latch,
+    /// A mute only covers the poll it was requested on; the next poll
+    /// that still sees the alert active sounds again.
+    Momentary,
+    /// A mute suppresses the tone for a fixed duration, then resumes even
+    /// if the same alert is still active.
+    Snooze,
+}
+
+impl Default for SilenceMode {
+    fn default() -> Self {
+        SilenceMode::Latch
+    }
+}
+
+/// Tracks a pending mute request and decides, per poll, whether the alert
+/// tone should sound given a [`SilenceMode`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlertSilence {
+    muted_since_ms: Option<u64>,
+}
+
+impl AlertSilence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call when the user taps/sends the silence command.
+    pub fn mute(&mut self, now_ms: u64) {
+        self.muted_since_ms = Some(now_ms);
+    }
+
+    /// Call once the alert list clears, so a latched mute doesn't carry
+    /// over and silence the next, unrelated alert.
+    pub fn clear(&mut self) {
+        self.muted_since_ms = None;
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted_since_ms.is_some()
+    }
+
+    /// Whether the tone should sound this poll. A [`SilenceMode::Momentary`]
+    /// mute consumes itself here (so the *next* call sounds again);
+    /// [`SilenceMode::Snooze`] expires after `snooze_ms`;
+    /// [`SilenceMode::Latch`] persists until [`Self::clear`].
+    pub fn should_sound(&mut self, mode: SilenceMode, now_ms: u64, snooze_ms: u64) -> bool {
+        let Some(muted_at) = self.muted_since_ms else {
+            return true;
+        };
+        match mode {
+            SilenceMode::Latch => false,
+            SilenceMode::Momentary => {
+                self.muted_since_ms = None;
+                false
+            }
+            SilenceMode::Snooze => {
+                if now_ms.saturating_sub(muted_at) < snooze_ms {
+                    false
+                } else {
+                    self.muted_since_ms = None;
+                    true
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmuted_always_sounds() {
+        let mut silence = AlertSilence::new();
+        assert!(silence.should_sound(SilenceMode::Latch, 0, 10_000));
+        assert!(silence.should_sound(SilenceMode::Momentary, 0, 10_000));
+        assert!(silence.should_sound(SilenceMode::Snooze, 0, 10_000));
+    }
+
+    #[test]
+    fn latch_stays_silent_across_every_poll_until_cleared() {
+        let mut silence = AlertSilence::new();
+        silence.mute(0);
+
+        assert!(!silence.should_sound(SilenceMode::Latch, 1_000, 10_000));
+        assert!(!silence.should_sound(SilenceMode::Latch, 10_000, 10_000));
+        assert!(!silence.should_sound(SilenceMode::Latch, 1_000_000, 10_000));
+
+        silence.clear();
+        assert!(silence.should_sound(SilenceMode::Latch, 1_000_001, 10_000));
+    }
+
+    #[test]
+    fn momentary_resounds_on_the_very_next_poll() {
+        let mut silence = AlertSilence::new();
+        silence.mute(0);
+
+        assert!(!silence.should_sound(SilenceMode::Momentary, 100, 10_000));
+        // Same persisting alert, next poll: mute was consumed.
+        assert!(silence.should_sound(SilenceMode::Momentary, 200, 10_000));
+    }
+
+    #[test]
+    fn snooze_resounds_once_the_duration_elapses() {
+        let mut silence = AlertSilence::new();
+        silence.mute(0);
+
+        assert!(!silence.should_sound(SilenceMode::Snooze, 5_000, 10_000));
+        assert!(!silence.should_sound(SilenceMode::Snooze, 9_999, 10_000));
+        assert!(silence.should_sound(SilenceMode::Snooze, 10_000, 10_000));
+    }
+}
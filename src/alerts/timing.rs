@@ -0,0 +1,81 @@
+//! Relative-time formatting for alert onset/effective/expiry timestamps,
+//! against the NTP-synced wall clock (`now_ms`). Shared by whichever line
+//! the warning view needs: "Starts in Xh", "Active now", "Expires in Xh".
+
+/// Formats the time between `now_ms` and a future `target_ms` as a short
+/// duration ("2h 30m", "45m", "now" for anything under a minute). Returns
+/// `None` if `target_ms` is not in the future.
+pub fn format_relative(now_ms: u64, target_ms: u64) -> Option<String> {
+    if target_ms <= now_ms {
+        return None;
+    }
+    let delta_s = (target_ms - now_ms) / 1000;
+    if delta_s < 60 {
+        return Some("now".to_string());
+    }
+    let hours = delta_s / 3600;
+    let minutes = (delta_s % 3600) / 60;
+    Some(if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    })
+}
+
+/// The onset/"starts in" line for the warning view: "Active now" once the
+/// hazard has started (or there's no onset info at all), otherwise
+/// "Starts in Xh Ym".
+pub fn onset_line(now_ms: u64, onset_at_ms: Option<u64>) -> String {
+    match onset_at_ms {
+        None => "Active now".to_string(),
+        Some(onset_ms) => match format_relative(now_ms, onset_ms) {
+            Some(relative) => format!("Starts in {relative}"),
+            None => "Active now".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn past_target_formats_to_none() {
+        assert_eq!(format_relative(10_000, 5_000), None);
+    }
+
+    #[test]
+    fn now_target_formats_to_none() {
+        assert_eq!(format_relative(10_000, 10_000), None);
+    }
+
+    #[test]
+    fn near_future_target_formats_as_now() {
+        assert_eq!(format_relative(0, 30_000), Some("now".to_string()));
+    }
+
+    #[test]
+    fn future_target_formats_as_hours_and_minutes() {
+        assert_eq!(format_relative(0, 2 * 3_600_000 + 30 * 60_000), Some("2h 30m".to_string()));
+    }
+
+    #[test]
+    fn future_target_under_an_hour_omits_the_hour_part() {
+        assert_eq!(format_relative(0, 45 * 60_000), Some("45m".to_string()));
+    }
+
+    #[test]
+    fn onset_line_is_active_now_when_onset_is_missing() {
+        assert_eq!(onset_line(1_000, None), "Active now");
+    }
+
+    #[test]
+    fn onset_line_is_active_now_when_onset_already_passed() {
+        assert_eq!(onset_line(10_000, Some(5_000)), "Active now");
+    }
+
+    #[test]
+    fn onset_line_shows_countdown_when_onset_is_future() {
+        assert_eq!(onset_line(0, Some(45 * 60_000)), "Starts in 45m");
+    }
+}
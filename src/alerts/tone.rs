@@ -0,0 +1,160 @@
+//! Alert tone selection and repeat-play scheduling. The scheduling here is
+//! pure (driven by timestamps the caller supplies), so it can be unit
+//! tested without an async worker or real speaker.
+
+use super::AlertKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertTone {
+    Advisory,
+    Watch,
+    Warning,
+    /// Played once when the alert list goes from non-empty to empty, so
+    /// clearing reads as positive feedback rather than just silence.
+    AllClear,
+}
+
+impl AlertTone {
+    pub fn from_request(kind: &str) -> Option<Self> {
+        match kind {
+            "advisory" => Some(AlertTone::Advisory),
+            "watch" => Some(AlertTone::Watch),
+            "warning" => Some(AlertTone::Warning),
+            "all_clear" => Some(AlertTone::AllClear),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlertTone::Advisory => "advisory",
+            AlertTone::Watch => "watch",
+            AlertTone::Warning => "warning",
+            AlertTone::AllClear => "all_clear",
+        }
+    }
+
+    pub fn for_kind(kind: AlertKind) -> Self {
+        match kind {
+            AlertKind::Advisory => AlertTone::Advisory,
+            AlertKind::Watch => AlertTone::Watch,
+            AlertKind::Warning => AlertTone::Warning,
+        }
+    }
+}
+
+/// (frequency_hz, duration_ms) pairs describing the all-clear chime, in
+/// play order: a higher note followed by a lower one reads as "resolved"
+/// rather than the rising pattern used for warnings.
+pub const ALL_CLEAR_CHIME_NOTES: [(f32, u32); 2] = [(880.0, 120), (660.0, 160)];
+
+/// Schedules repeated plays of a single [`AlertTone`] at a fixed gap,
+/// until either `repeat` plays have happened or [`RepeatPlayer::silence`]
+/// is called (mirroring a silence/stop command from the console or touch
+/// UI interrupting mid-repeat).
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatPlayer {
+    tone: AlertTone,
+    repeat: u32,
+    gap_ms: u64,
+    played: u32,
+    next_play_ms: u64,
+    silenced: bool,
+}
+
+impl RepeatPlayer {
+    /// Starts a schedule that plays immediately (`now_ms`), then every
+    /// `gap_ms` after, up to `repeat` total plays.
+    pub fn new(tone: AlertTone, repeat: u32, gap_ms: u64, now_ms: u64) -> Self {
+        Self {
+            tone,
+            repeat,
+            gap_ms,
+            played: 0,
+            next_play_ms: now_ms,
+            silenced: false,
+        }
+    }
+
+    /// Call on every loop tick. Returns the tone to play right now, if a
+    /// play is due, advancing the schedule for the next call.
+    pub fn tick(&mut self, now_ms: u64) -> Option<AlertTone> {
+        if self.silenced || self.is_done() || now_ms < self.next_play_ms {
+            return None;
+        }
+        self.played += 1;
+        self.next_play_ms = now_ms + self.gap_ms;
+        Some(self.tone)
+    }
+
+    /// Stops the schedule immediately; subsequent `tick` calls return
+    /// `None` regardless of how many repeats remain.
+    pub fn silence(&mut self) {
+        self.silenced = true;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.silenced || self.played >= self.repeat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_request_parses_known_kinds() {
+        assert_eq!(AlertTone::from_request("warning"), Some(AlertTone::Warning));
+        assert_eq!(AlertTone::from_request("advisory"), Some(AlertTone::Advisory));
+        assert_eq!(AlertTone::from_request("bogus"), None);
+    }
+
+    #[test]
+    fn as_str_round_trips_through_from_request() {
+        for tone in [
+            AlertTone::Advisory,
+            AlertTone::Watch,
+            AlertTone::Warning,
+            AlertTone::AllClear,
+        ] {
+            assert_eq!(AlertTone::from_request(tone.as_str()), Some(tone));
+        }
+    }
+
+    #[test]
+    fn all_clear_parses_from_request() {
+        assert_eq!(AlertTone::from_request("all_clear"), Some(AlertTone::AllClear));
+        assert_eq!(AlertTone::AllClear.as_str(), "all_clear");
+    }
+
+    #[test]
+    fn plays_immediately_then_waits_for_the_gap() {
+        let mut player = RepeatPlayer::new(AlertTone::Warning, 3, 1_000, 0);
+
+        assert_eq!(player.tick(0), Some(AlertTone::Warning));
+        assert_eq!(player.tick(500), None);
+        assert_eq!(player.tick(1_000), Some(AlertTone::Warning));
+        assert_eq!(player.tick(2_000), Some(AlertTone::Warning));
+    }
+
+    #[test]
+    fn stops_after_repeat_count_is_exhausted() {
+        let mut player = RepeatPlayer::new(AlertTone::Advisory, 2, 100, 0);
+
+        assert_eq!(player.tick(0), Some(AlertTone::Advisory));
+        assert_eq!(player.tick(100), Some(AlertTone::Advisory));
+        assert!(player.is_done());
+        assert_eq!(player.tick(200), None);
+    }
+
+    #[test]
+    fn silence_interrupts_mid_repeat() {
+        let mut player = RepeatPlayer::new(AlertTone::Warning, 5, 100, 0);
+
+        assert_eq!(player.tick(0), Some(AlertTone::Warning));
+        player.silence();
+
+        assert_eq!(player.tick(100), None);
+        assert!(player.is_done());
+    }
+}
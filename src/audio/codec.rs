@@ -0,0 +1,155 @@
+//! ES8311 codec register access over I2C: the control path alongside
+//! [`super::Speaker`]'s I2S data path. A real codec init sequence (the
+//! power-up register writes, sample-rate-dependent clock dividers) isn't
+//! wired up yet — the only register access in real use today is
+//! [`self_test`]'s chip-ID reads at boot.
+
+use crate::i2c_bus::{I2cConfig, RegisterBus};
+
+/// ES8311's fixed 7-bit I2C address.
+pub const ES8311_I2C_ADDR: u8 = 0x18;
+
+/// Chip ID registers; reading these back is how [`super::Speaker`]'s
+/// self-test confirms the codec is actually present and responding.
+pub const REG_CHIP_ID1: u8 = 0xFD;
+pub const REG_CHIP_ID2: u8 = 0xFE;
+
+/// The value `REG_CHIP_ID1` reads back as on a genuine ES8311.
+pub const EXPECTED_CHIP_ID1: u8 = 0x83;
+
+/// Writes a single ES8311 register using `config`'s clock/timeout.
+pub fn write_reg<B: RegisterBus>(bus: &mut B, reg: u8, value: u8, config: I2cConfig) -> Result<(), B::Error> {
+    bus.write_reg(ES8311_I2C_ADDR, reg, value, config)
+}
+
+/// Reads a single ES8311 register using `config`'s clock/timeout.
+pub fn read_reg<B: RegisterBus>(bus: &mut B, reg: u8, config: I2cConfig) -> Result<u8, B::Error> {
+    bus.read_reg(ES8311_I2C_ADDR, reg, config)
+}
+
+/// Init-time register reads are flaky on some boards (the codec is still
+/// settling right after power-up), so attempts are retried a few times
+/// before giving up, rather than failing the whole init sequence on one
+/// transient NACK.
+const MAX_INIT_ATTEMPTS: u32 = 3;
+const RETRY_DELAY_MS: u32 = 5;
+
+/// Reads an ES8311 register as part of init, retrying up to
+/// [`MAX_INIT_ATTEMPTS`] times with a short delay between attempts. Logs
+/// the register address once retries are exhausted.
+pub fn read_reg_with_retry<B: RegisterBus>(
+    bus: &mut B,
+    reg: u8,
+    config: I2cConfig,
+) -> Result<u8, B::Error> {
+    let mut last_err = None;
+    for attempt in 1..=MAX_INIT_ATTEMPTS {
+        match read_reg(bus, reg, config) {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                last_err = Some(err);
+                if attempt < MAX_INIT_ATTEMPTS {
+                    std::thread::sleep(std::time::Duration::from_millis(RETRY_DELAY_MS as u64));
+                }
+            }
+        }
+    }
+    log::warn!("ES8311 init: giving up on register 0x{reg:02X} after {MAX_INIT_ATTEMPTS} attempts");
+    Err(last_err.expect("loop always runs at least once"))
+}
+
+/// Result of reading back the ES8311's chip ID registers, used by
+/// [`super::Speaker::self_test`] to report pass/fail without the caller
+/// needing to know the expected register values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfTest {
+    pub chip_id1: u8,
+    pub chip_id2: u8,
+    pub passed: bool,
+}
+
+/// Reads back the ES8311's chip ID registers and checks them against the
+/// known-good value, to confirm the codec is present and wired correctly
+/// before relying on it for audio output.
+pub fn self_test<B: RegisterBus>(bus: &mut B, config: I2cConfig) -> Result<SelfTest, B::Error> {
+    let chip_id1 = read_reg_with_retry(bus, REG_CHIP_ID1, config)?;
+    let chip_id2 = read_reg_with_retry(bus, REG_CHIP_ID2, config)?;
+    Ok(SelfTest {
+        chip_id1,
+        chip_id2,
+        passed: chip_id1 == EXPECTED_CHIP_ID1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i2c_bus::mock::{Call, MockI2cBus};
+
+    #[test]
+    fn write_reg_targets_the_es8311_address() {
+        let mut bus = MockI2cBus::new();
+        write_reg(&mut bus, 0x00, 0x80, I2cConfig::default()).unwrap();
+        assert_eq!(
+            bus.calls,
+            vec![Call::Write {
+                addr: ES8311_I2C_ADDR,
+                reg: 0x00,
+                value: 0x80,
+                timeout_ms: I2cConfig::default().timeout_ms,
+            }]
+        );
+    }
+
+    #[test]
+    fn read_reg_targets_the_es8311_address() {
+        let mut bus = MockI2cBus::new();
+        bus.read_results.push_back(Ok(EXPECTED_CHIP_ID1));
+        let value = read_reg(&mut bus, REG_CHIP_ID1, I2cConfig::default()).unwrap();
+        assert_eq!(value, EXPECTED_CHIP_ID1);
+        assert_eq!(
+            bus.calls,
+            vec![Call::Read {
+                addr: ES8311_I2C_ADDR,
+                reg: REG_CHIP_ID1,
+                timeout_ms: I2cConfig::default().timeout_ms,
+            }]
+        );
+    }
+
+    #[test]
+    fn read_with_retry_succeeds_after_one_failure() {
+        let mut bus = MockI2cBus::new();
+        bus.read_results.push_back(Err("nack"));
+        bus.read_results.push_back(Ok(EXPECTED_CHIP_ID1));
+
+        let value = read_reg_with_retry(&mut bus, REG_CHIP_ID1, I2cConfig::default()).unwrap();
+
+        assert_eq!(value, EXPECTED_CHIP_ID1);
+        assert_eq!(bus.calls.len(), 2);
+    }
+
+    #[test]
+    fn self_test_passes_with_the_expected_chip_id() {
+        let mut bus = MockI2cBus::new();
+        bus.read_results.push_back(Ok(EXPECTED_CHIP_ID1));
+        bus.read_results.push_back(Ok(0x16));
+
+        let result = self_test(&mut bus, I2cConfig::default()).unwrap();
+
+        assert!(result.passed);
+        assert_eq!(result.chip_id1, EXPECTED_CHIP_ID1);
+        assert_eq!(result.chip_id2, 0x16);
+    }
+
+    #[test]
+    fn self_test_fails_with_an_unexpected_chip_id() {
+        let mut bus = MockI2cBus::new();
+        bus.read_results.push_back(Ok(0xFF));
+        bus.read_results.push_back(Ok(0x00));
+
+        let result = self_test(&mut bus, I2cConfig::default()).unwrap();
+
+        assert!(!result.passed);
+    }
+}
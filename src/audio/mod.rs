@@ -0,0 +1,106 @@
+//! Speaker output: I2S/ES8311 driver wrapper and tone generation for
+//! alert chimes and the self-test.
+
+pub mod codec;
+mod tone;
+
+pub use codec::SelfTest;
+pub use tone::{Envelope, generate_tone};
+
+use crate::i2c_bus::{I2cConfig, RegisterBus};
+use esp_idf_hal::i2s::{config::StdClkConfig, I2sDriver};
+
+/// Frequency/duration of the inaudible-ish confirmation tick played after
+/// a passing self-test, short and quiet enough not to startle anyone
+/// standing near the unit at boot.
+const SELF_TEST_TICK_FREQ_HZ: f32 = 1000.0;
+const SELF_TEST_TICK_DURATION_MS: u32 = 30;
+
+/// Default I2S sample rate, matching the ES8311 codec's default init
+/// sequence. Callers can pick a different rate (e.g. 16 kHz to cut DMA
+/// load for alert tones), but the ES8311 must be reconfigured to match or
+/// playback will be pitched/sped up.
+pub const DEFAULT_SAMPLE_RATE_HZ: u32 = 48_000;
+
+pub struct Speaker<'d> {
+    i2s: I2sDriver<'d, esp_idf_hal::i2s::I2sBiDir>,
+    sample_rate_hz: u32,
+}
+
+impl<'d> Speaker<'d> {
+    /// `sample_rate_hz` drives both the I2S `StdClkConfig` and the tone
+    /// synthesis math. If it isn't [`DEFAULT_SAMPLE_RATE_HZ`], the caller
+    /// is responsible for reconfiguring the ES8311 codec to match (its
+    /// init sequence otherwise assumes 48 kHz).
+    pub fn new(mut i2s: I2sDriver<'d, esp_idf_hal::i2s::I2sBiDir>, sample_rate_hz: u32) -> Self {
+        if sample_rate_hz != DEFAULT_SAMPLE_RATE_HZ {
+            log::warn!(
+                "I2S sample rate set to {sample_rate_hz}Hz; ES8311 codec init assumes {DEFAULT_SAMPLE_RATE_HZ}Hz and must be reconfigured to match"
+            );
+        }
+        let clk_cfg = StdClkConfig::from_sample_rate_hz(sample_rate_hz);
+        i2s.rx_set_std_clk(&clk_cfg);
+        Self {
+            i2s,
+            sample_rate_hz,
+        }
+    }
+
+    pub fn sample_rate_hz(&self) -> u32 {
+        self.sample_rate_hz
+    }
+
+    /// Generates and plays a single tone.
+    pub fn play_tone(
+        &mut self,
+        freq_hz: f32,
+        duration_ms: u32,
+        envelope: Envelope,
+    ) -> anyhow::Result<()> {
+        let samples = generate_tone(freq_hz, duration_ms, self.sample_rate_hz, envelope);
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        self.i2s.write(&bytes, esp_idf_hal::delay::BLOCK)?;
+        Ok(())
+    }
+
+    /// Plays a sequence of notes back-to-back, e.g.
+    /// [`crate::alerts::tone::ALL_CLEAR_CHIME_NOTES`].
+    pub fn play_chime(&mut self, notes: &[(f32, u32)], envelope: Envelope) -> anyhow::Result<()> {
+        for &(freq_hz, duration_ms) in notes {
+            self.play_tone(freq_hz, duration_ms, envelope)?;
+        }
+        Ok(())
+    }
+
+    /// Confirms the ES8311 is present and correctly wired by reading back
+    /// its chip ID over `bus`, then playing a brief quiet tick to confirm
+    /// the I2S data path too if the ID check passed. Called once at boot.
+    pub fn self_test<B: RegisterBus>(&mut self, bus: &mut B, config: I2cConfig) -> anyhow::Result<SelfTest>
+    where
+        B::Error: std::error::Error + Send + Sync + 'static,
+    {
+        let result = codec::self_test(bus, config)?;
+        if result.passed {
+            // Scaled well below the full-amplitude samples `play_tone` sends.
+            const SELF_TEST_TICK_GAIN: f32 = 0.1;
+            let samples = generate_tone(
+                SELF_TEST_TICK_FREQ_HZ,
+                SELF_TEST_TICK_DURATION_MS,
+                self.sample_rate_hz,
+                Envelope::default(),
+            );
+            let bytes: Vec<u8> = samples
+                .iter()
+                .flat_map(|s| ((*s as f32 * SELF_TEST_TICK_GAIN) as i16).to_le_bytes())
+                .collect();
+            self.i2s.write(&bytes, esp_idf_hal::delay::BLOCK)?;
+        } else {
+            log::warn!(
+                "speaker self-test failed: chip_id1=0x{:02X} (expected 0x{:02X})",
+                result.chip_id1,
+                codec::EXPECTED_CHIP_ID1
+            );
+        }
+        Ok(result)
+    }
+}
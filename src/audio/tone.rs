@@ -0,0 +1,128 @@
+//! Pure tone generation: a sine wave at a given frequency/duration/sample
+//! rate, with an amplitude envelope to avoid clicks at the start/end of
+//! short alert beeps.
+
+use std::f32::consts::PI;
+
+/// Linear attack/release ramp lengths, in milliseconds, applied to a
+/// tone's amplitude. Generalizes a fixed `sample_rate / 200` fade so
+/// callers can tune
+/// ramp length per tone (longer for low-pitched alert tones, shorter for
+/// clicks/taps).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    pub attack_ms: u32,
+    pub release_ms: u32,
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        // Matches the old fixed fade: at a 16kHz sample rate,
+        // sample_rate/200 = 80 samples ~= 5ms.
+        Self {
+            attack_ms: 5,
+            release_ms: 5,
+        }
+    }
+}
+
+/// Amplitude gain (0.0-1.0) at time `t` seconds into a tone of total
+/// `duration_s` seconds, given attack/release ramp lengths in seconds.
+fn envelope_gain(envelope: Envelope, t: f32, duration_s: f32) -> f32 {
+    let attack_s = (envelope.attack_ms as f32 / 1000.0).min(duration_s / 2.0);
+    let release_s = (envelope.release_ms as f32 / 1000.0).min(duration_s / 2.0);
+
+    if attack_s > 0.0 && t < attack_s {
+        t / attack_s
+    } else if release_s > 0.0 && t > duration_s - release_s {
+        (duration_s - t) / release_s
+    } else {
+        1.0
+    }
+}
+
+/// Number of samples in one full cycle of `freq_hz` at `sample_rate_hz`.
+/// Used to size lookup-table/period-counter based waveforms; scales
+/// inversely with the configured sample rate for a fixed frequency.
+pub fn period_samples(freq_hz: f32, sample_rate_hz: u32) -> u32 {
+    (sample_rate_hz as f32 / freq_hz).round() as u32
+}
+
+/// Generates `duration_ms` of a `freq_hz` sine wave at `sample_rate_hz`, as
+/// signed 16-bit PCM samples, shaped by `envelope`.
+pub fn generate_tone(
+    freq_hz: f32,
+    duration_ms: u32,
+    sample_rate_hz: u32,
+    envelope: Envelope,
+) -> Vec<i16> {
+    let n_samples = (sample_rate_hz as u64 * duration_ms as u64 / 1000) as usize;
+    let duration_s = duration_ms as f32 / 1000.0;
+    (0..n_samples)
+        .map(|i| {
+            let t = i as f32 / sample_rate_hz as f32;
+            let gain = envelope_gain(envelope, t, duration_s);
+            let sample = (2.0 * PI * freq_hz * t).sin() * gain;
+            (sample * i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_count_matches_duration_and_rate() {
+        let samples = generate_tone(440.0, 100, 16_000, Envelope::default());
+        assert_eq!(samples.len(), 1_600);
+    }
+
+    #[test]
+    fn gain_at_attack_start_is_zero() {
+        let env = Envelope {
+            attack_ms: 10,
+            release_ms: 10,
+        };
+        assert_eq!(envelope_gain(env, 0.0, 0.1), 0.0);
+    }
+
+    #[test]
+    fn gain_mid_tone_is_full() {
+        let env = Envelope {
+            attack_ms: 10,
+            release_ms: 10,
+        };
+        assert_eq!(envelope_gain(env, 0.05, 0.1), 1.0);
+    }
+
+    #[test]
+    fn gain_at_release_end_is_zero() {
+        let env = Envelope {
+            attack_ms: 10,
+            release_ms: 10,
+        };
+        assert!(envelope_gain(env, 0.1, 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn period_scales_with_sample_rate() {
+        // Cutting the sample rate to a third cuts the period (in samples)
+        // to a third for the same frequency.
+        let period_48k = period_samples(1_000.0, 48_000);
+        let period_16k = period_samples(1_000.0, 16_000);
+        assert_eq!(period_48k, 48);
+        assert_eq!(period_16k, 16);
+        assert_eq!(period_48k / 3, period_16k);
+    }
+
+    #[test]
+    fn zero_length_ramps_behave_like_no_envelope() {
+        let env = Envelope {
+            attack_ms: 0,
+            release_ms: 0,
+        };
+        assert_eq!(envelope_gain(env, 0.0, 0.1), 1.0);
+        assert_eq!(envelope_gain(env, 0.1, 0.1), 1.0);
+    }
+}
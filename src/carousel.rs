@@ -0,0 +1,59 @@
+//! Auto-advance "carousel" mode: cycles pages on a timer, but backs off for
+//! a while after any manual swipe so it doesn't fight the user.
+
+/// How long after a manual interaction before auto-advance resumes.
+pub const RESUME_AFTER_INTERACTION_MS: u64 = 15_000;
+
+/// Whether the carousel should advance to the next page right now, given
+/// the last page-change time, the last manual-interaction time, and the
+/// configured interval. All timestamps are in the same monotonic millis
+/// base (see `time::now_ms`).
+pub fn should_advance(
+    now_ms: u64,
+    last_page_change_ms: u64,
+    last_interaction_ms: u64,
+    interval_ms: u64,
+    enabled: bool,
+) -> bool {
+    if !enabled {
+        return false;
+    }
+    if now_ms.saturating_sub(last_interaction_ms) < RESUME_AFTER_INTERACTION_MS {
+        return false;
+    }
+    now_ms.saturating_sub(last_page_change_ms) >= interval_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advances_after_interval_elapses() {
+        // Last interaction is past the resume pause, so only the interval decides.
+        assert!(should_advance(20_000, 10_000, 0, 8_000, true));
+    }
+
+    #[test]
+    fn does_not_advance_before_interval() {
+        // Last interaction is past the resume pause, so only the interval decides.
+        assert!(!should_advance(20_000, 15_000, 0, 8_000, true));
+    }
+
+    #[test]
+    fn paused_shortly_after_interaction() {
+        // Interval has elapsed, but the user just interacted.
+        assert!(!should_advance(20_000, 0, 19_000, 8_000, true));
+    }
+
+    #[test]
+    fn resumes_once_interaction_pause_expires() {
+        let now = RESUME_AFTER_INTERACTION_MS + 9_000;
+        assert!(should_advance(now, 0, 0, 8_000, true));
+    }
+
+    #[test]
+    fn disabled_never_advances() {
+        assert!(!should_advance(1_000_000, 0, 0, 1, false));
+    }
+}
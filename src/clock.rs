@@ -0,0 +1,93 @@
+//! A small abstraction over "what time is it", so time-dependent logic
+//! (HVAC state duration, snooze, staleness) can be exercised with a
+//! [`MockClock`] in host-side tests instead of real elapsed wall time.
+//! Most of this crate's time-dependent functions already take `now_ms: u64`
+//! as a plain argument rather than querying a clock internally, which is
+//! what makes them testable in the first place; this trait exists for the
+//! handful of call sites — starting with `main`'s loop — that otherwise
+//! have to reach for a real [`std::time::Instant`].
+
+use std::time::Instant;
+
+pub trait Clock {
+    /// Milliseconds since some fixed, implementation-defined epoch (boot,
+    /// for [`SystemClock`]; whatever a test sets up, for [`MockClock`]).
+    /// Guaranteed non-decreasing across successive calls on the same
+    /// instance — callers may rely on `now_ms()` never going backwards.
+    fn now_ms(&self) -> u64;
+}
+
+/// Milliseconds elapsed since a fixed [`Instant`] captured at construction
+/// (typically once, at boot). Monotonic, since `Instant::elapsed` can never
+/// go backwards.
+pub struct SystemClock {
+    epoch: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self { epoch: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        self.epoch.elapsed().as_millis() as u64
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests of
+/// time-dependent logic.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MockClock {
+    now_ms: u64,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance_ms(&mut self, delta_ms: u64) {
+        self.now_ms += delta_ms;
+    }
+}
+
+impl Clock for MockClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_clock_starts_at_zero_and_advances_on_demand() {
+        let mut clock = MockClock::new();
+        assert_eq!(clock.now_ms(), 0);
+        clock.advance_ms(500);
+        assert_eq!(clock.now_ms(), 500);
+        clock.advance_ms(250);
+        assert_eq!(clock.now_ms(), 750);
+    }
+
+    #[test]
+    fn successive_reads_are_non_decreasing() {
+        let mut clock = MockClock::new();
+        let mut last = clock.now_ms();
+        for delta in [0, 10, 0, 40] {
+            clock.advance_ms(delta);
+            let now = clock.now_ms();
+            assert!(now >= last);
+            last = now;
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::display::page::{self, Page};
+use crate::graph::GraphStyle;
+use crate::settings::SettingsBlob;
+use crate::touch::Orientation;
+
+/// Where `active_alerts` is fetched from. OWM is the default: it's
+/// already the weather source and covers most of the world, whereas NWS
+/// only covers the US but provides richer alert metadata there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertSource {
+    Owm,
+    Nws,
+}
+
+/// A saved weather location: a friendly label plus the OWM city id to
+/// query for it.
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub name: String,
+    pub owm_city_id: String,
+}
+
+/// User-configurable application settings. Grows as new features land; all
+/// fields should have sane defaults so a blank NVS namespace still boots.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub owm_api_key: String,
+    /// Saved locations for quick switching; the first is the default.
+    pub locations: Vec<Location>,
+    pub active_location: usize,
+    /// Pages shown by swipe navigation, in display order. Users can disable
+    /// (omit) or reorder pages; an empty list disables swipe navigation
+    /// entirely rather than panicking.
+    pub enabled_pages: Vec<Page>,
+    /// Station altitude in meters, for sea-level pressure normalization.
+    pub station_altitude_m: f32,
+    pub pressure_graph_style: GraphStyle,
+    pub screen_orientation: Orientation,
+    /// Whether page changes slide (see [`crate::display::transition`]) or
+    /// snap instantly. Meant to be turned off in low-power scenarios,
+    /// where every extra redrawn frame costs battery.
+    pub animations_enabled: bool,
+    /// Whether the pressure view overlays outdoor OWM temperature on its
+    /// own right-hand Y axis (see [`crate::graph::draw_overlay_line`]).
+    pub show_outdoor_temp_overlay: bool,
+    /// Maximum redraw rate when nothing on screen is dirty (see
+    /// [`crate::redraw::RedrawThrottle`]); a dirty view always redraws
+    /// regardless of this cap. `0` disables timed redraws entirely.
+    pub max_redraw_fps: u32,
+    /// Which source `active_alerts` is polled from (see
+    /// [`crate::alerts::nws`] for the NWS option).
+    pub alert_source: AlertSource,
+    /// Whether to fire a lightweight DNS/connection pre-warm shortly after
+    /// Wi-Fi connects (see [`crate::net::prewarm::WifiPrewarm`]), so the
+    /// first real fetch doesn't pay for a cold TLS handshake. Off by
+    /// default since it spends a little extra radio time right after
+    /// connecting.
+    pub wifi_prewarm_enabled: bool,
+    /// Units and alert thresholds, persisted together (see
+    /// [`crate::settings::SettingsBlob`]).
+    pub settings: SettingsBlob,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            owm_api_key: String::new(),
+            locations: vec![Location {
+                name: "Home".to_string(),
+                owm_city_id: String::new(),
+            }],
+            active_location: 0,
+            enabled_pages: page::ALL.to_vec(),
+            station_altitude_m: 0.0,
+            pressure_graph_style: GraphStyle::default(),
+            screen_orientation: Orientation::Landscape,
+            animations_enabled: true,
+            show_outdoor_temp_overlay: false,
+            max_redraw_fps: 30,
+            alert_source: AlertSource::Owm,
+            wifi_prewarm_enabled: false,
+            settings: SettingsBlob::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn active_location(&self) -> Option<&Location> {
+        self.locations.get(self.active_location)
+    }
+
+    /// Switches the active location, clamped to the saved list so an
+    /// out-of-range index (e.g. after a location was deleted) doesn't
+    /// panic on the next lookup.
+    pub fn set_active_location(&mut self, index: usize) {
+        if self.locations.is_empty() {
+            return;
+        }
+        self.active_location = index.min(self.locations.len() - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switching_location_clamps_to_saved_list() {
+        let mut config = AppConfig {
+            locations: vec![
+                Location { name: "A".into(), owm_city_id: "1".into() },
+                Location { name: "B".into(), owm_city_id: "2".into() },
+            ],
+            ..AppConfig::default()
+        };
+        config.set_active_location(5);
+        assert_eq!(config.active_location, 1);
+        assert_eq!(config.active_location().unwrap().name, "B");
+    }
+}
@@ -0,0 +1,270 @@
+//! Minimal line-oriented command console, read from the UART/USB-serial
+//! input and used for on-device debugging without a full UI flow.
+
+use crate::state::AppState;
+
+/// Parses and applies a single console command line. Unknown commands are
+/// reported but otherwise ignored.
+pub fn handle_line(line: &str, state: &mut AppState) {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+        Some("carousel") => handle_carousel(&mut parts, state),
+        Some("animations") => handle_animations(&mut parts, state),
+        Some("graph") => handle_graph(&mut parts, state),
+        Some("sleepmode") => handle_sleepmode(&mut parts, state),
+        Some("location") => {
+            let idx: usize = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+            state.config.set_active_location(idx);
+            if let Some(loc) = state.config.active_location() {
+                log::info!("switched to location: {}", loc.name);
+            }
+        }
+        Some("factory-reset") => {
+            log::warn!("factory reset requested; erasing NVS and rebooting");
+            state.pending_factory_reset = true;
+        }
+        Some("mem") => log::info!(
+            "{}, psram high water: {}",
+            crate::diagnostics::heap_report(),
+            match state.psram_high_water_bytes {
+                Some(bytes) => format!("{bytes} bytes"),
+                // No fetch pipeline owns a `PsramBuf` yet to ever record one.
+                None => "not yet tracked".to_string(),
+            }
+        ),
+        Some("hvac") => handle_hvac(&mut parts, state),
+        Some("testalert") => handle_testalert(&mut parts, state),
+        Some("replayweather") => handle_replayweather(&mut parts, state),
+        Some("hvactrans") => {
+            let transitions: heapless::Vec<_, 20> =
+                state.hvac_detector.recent_transitions().copied().collect();
+            match serde_json::to_string(&transitions) {
+                Ok(json) => log::info!("{json}"),
+                Err(e) => log::error!("failed to serialize hvac transitions: {e}"),
+            }
+        }
+        Some("goto") => {
+            let idx: usize = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+            state.tester_state.goto(idx);
+            log::info!("tester: goto {}", state.tester_state.current_index());
+        }
+        Some("rerun") => {
+            state.tester_state.rerun();
+            log::info!("tester: rerun index {}", state.tester_state.current_index());
+        }
+        Some("skip") => {
+            state.tester_state.skip();
+            log::info!("tester: skipped to index {}", state.tester_state.current_index());
+        }
+        Some("tone") => {
+            let freq: f32 = parts.next().and_then(|s| s.parse().ok()).unwrap_or(440.0);
+            let duration_ms: u32 = parts.next().and_then(|s| s.parse().ok()).unwrap_or(200);
+            log::info!("tone: {freq}Hz for {duration_ms}ms");
+            let _ = crate::audio::generate_tone(
+                freq,
+                duration_ms,
+                crate::audio::DEFAULT_SAMPLE_RATE_HZ,
+                crate::audio::Envelope::default(),
+            );
+        }
+        Some("ota") => match parts.next() {
+            Some(url) => {
+                if let Err(e) = crate::net::ota::update_from_url(url) {
+                    log::error!("OTA update failed: {e}");
+                }
+            }
+            None => log::warn!("usage: ota <url>"),
+        },
+        Some(other) => log::warn!("unknown console command: {other}"),
+        None => {}
+    }
+}
+
+/// Persists `state.config.settings` via `state.nvs_store`, if one was
+/// opened at boot. Called by every handler that mutates `config.settings`
+/// so a change survives a reboot instead of only lasting until the next
+/// one; a missing store or a write failure is logged, not propagated,
+/// since the in-memory setting is already applied either way.
+fn persist_settings(state: &mut AppState) {
+    let Some(store) = state.nvs_store.as_mut() else {
+        return;
+    };
+    if let Err(e) = state.config.settings.save(store) {
+        log::error!("failed to persist settings: {e}");
+    }
+}
+
+fn handle_hvac<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match parts.next() {
+        Some("stats") => {
+            let samples: heapless::Vec<_, { crate::hvac::TIMELINE_CAPACITY }> =
+                state.hvac_timeline.iter().copied().collect();
+            let stats = crate::hvac::compute_stats(&samples);
+            match serde_json::to_string(&stats) {
+                Ok(json) => log::info!("{json}"),
+                Err(e) => log::error!("failed to serialize hvac stats: {e}"),
+            }
+        }
+        Some("debug") => {
+            let snapshot = state.hvac_detector.snapshot();
+            log::info!("{snapshot:?}");
+        }
+        Some("set") => handle_hvac_set(parts, state),
+        Some("logging") => match parts.next() {
+            Some("on") => {
+                state.hvac_detector.set_debug_logging(true);
+                log::info!("hvac transition logging enabled");
+            }
+            Some("off") => {
+                state.hvac_detector.set_debug_logging(false);
+                log::info!("hvac transition logging disabled");
+            }
+            _ => log::warn!("usage: hvac logging <on|off>"),
+        },
+        _ => log::warn!("usage: hvac <stats|debug|set|logging>"),
+    }
+}
+
+/// `hvac set hvac_on_slope <value>`: the value is entered in the user's
+/// current display unit (e.g. °F/min if `Units::Imperial`) and converted
+/// to °C/min before being stored, since the detector always runs in
+/// Celsius internally.
+fn handle_hvac_set<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match parts.next() {
+        Some("hvac_on_slope") => {
+            let Some(value) = parts.next().and_then(|s| s.parse::<f32>().ok()) else {
+                log::warn!("usage: hvac set hvac_on_slope <value>");
+                return;
+            };
+            let units = state.config.settings.units;
+            let c_per_min = units.slope_to_c_per_min(value);
+            state.config.settings.thresholds.hvac_slope_threshold_c_per_min = c_per_min;
+            state.hvac_detector.set_slope_threshold_c_per_min(c_per_min);
+            persist_settings(state);
+            log::info!("hvac_on_slope set to {c_per_min:.4} C/min (entered as {value} in {units:?})");
+        }
+        Some("history_period_secs") => {
+            let Some(secs) = parts.next().and_then(|s| s.parse::<u64>().ok()) else {
+                log::warn!("usage: hvac set history_period_secs <secs>");
+                return;
+            };
+            state.hvac_timeline.set_sample_period_secs(secs);
+            let span_hours =
+                secs as f32 * crate::hvac::TIMELINE_CAPACITY as f32 / 3_600.0;
+            log::info!("hvac history period set to {secs}s ({span_hours:.1}h window)");
+        }
+        _ => log::warn!("usage: hvac set <hvac_on_slope|history_period_secs> <value>"),
+    }
+}
+
+/// `testalert <advisory|watch|warning> <headline words...>`: injects a
+/// synthetic alert into `state.active_alerts`, for exercising the warnings
+/// page and alert tones without a live NWS/OWM feed.
+fn handle_testalert<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    let Some(kind_str) = parts.next() else {
+        log::warn!("usage: testalert <advisory|watch|warning> <headline...>");
+        return;
+    };
+    let Some(kind) = crate::alerts::AlertKind::from_str(kind_str) else {
+        log::warn!("unknown alert kind: {kind_str}");
+        return;
+    };
+    let headline: String = parts.collect::<Vec<_>>().join(" ");
+    if headline.is_empty() {
+        log::warn!("usage: testalert <advisory|watch|warning> <headline...>");
+        return;
+    }
+
+    let alert = crate::alerts::build_synthetic(kind, &headline, state.now_ms);
+    log::info!("injected synthetic {kind:?} alert: {}", alert.headline);
+    state.active_alerts.push(alert);
+    state
+        .active_alerts
+        .sort_by(|a, b| b.kind.severity_rank().cmp(&a.kind.severity_rank()));
+}
+
+/// `replayweather <chunk...>`: feeds one chunk of a (possibly multi-line)
+/// captured OWM response into `state.weather_replay`; once the chunks
+/// reassemble into a complete JSON object it's parsed through the same
+/// [`crate::weather::Weather::from_owm_json`] path a live fetch uses, and
+/// the result both logged and applied to `state.weather`.
+fn handle_replayweather<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    let chunk: String = parts.collect::<Vec<_>>().join(" ");
+    if chunk.is_empty() {
+        log::warn!("usage: replayweather <json chunk...>");
+        return;
+    }
+    let Some(payload) = state.weather_replay.feed(&chunk) else {
+        log::info!("replayweather: buffering, payload not complete yet");
+        return;
+    };
+    match crate::weather::Weather::from_owm_json(&payload) {
+        Ok(weather) => {
+            log::info!("replayweather: parsed {weather:?}");
+            state.weather = Some(weather);
+        }
+        Err(e) => log::error!("replayweather: failed to parse reassembled payload: {e}"),
+    }
+}
+
+fn handle_animations<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match parts.next() {
+        Some("on") => {
+            state.config.animations_enabled = true;
+            log::info!("page slide animations enabled");
+        }
+        Some("off") => {
+            state.config.animations_enabled = false;
+            log::info!("page slide animations disabled");
+        }
+        _ => log::warn!("usage: animations <on|off>"),
+    }
+}
+
+fn handle_graph<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match (parts.next(), parts.next()) {
+        (Some("smooth"), Some("on")) => {
+            state.config.settings.graph_smoothing_enabled = true;
+            persist_settings(state);
+            log::info!("pressure graph smoothing enabled");
+        }
+        (Some("smooth"), Some("off")) => {
+            state.config.settings.graph_smoothing_enabled = false;
+            persist_settings(state);
+            log::info!("pressure graph smoothing disabled");
+        }
+        _ => log::warn!("usage: graph smooth <on|off>"),
+    }
+}
+
+fn handle_sleepmode<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match parts.next() {
+        Some("on") => {
+            let poll_mins: u32 = parts.next().and_then(|s| s.parse().ok()).unwrap_or(10);
+            state.sleep_mode_enabled = true;
+            state.sleep_poll_interval_mins = poll_mins;
+            log::info!("sleep mode enabled, waking every {poll_mins}min");
+        }
+        Some("off") => {
+            state.sleep_mode_enabled = false;
+            log::info!("sleep mode disabled");
+        }
+        _ => log::warn!("usage: sleepmode <on <poll_mins>|off>"),
+    }
+}
+
+fn handle_carousel<'a>(parts: &mut impl Iterator<Item = &'a str>, state: &mut AppState) {
+    match parts.next() {
+        Some("on") => {
+            let secs: u64 = parts.next().and_then(|s| s.parse().ok()).unwrap_or(10);
+            state.carousel_enabled = true;
+            state.carousel_interval_ms = secs * 1_000;
+            log::info!("carousel enabled, interval={secs}s");
+        }
+        Some("off") => {
+            state.carousel_enabled = false;
+            log::info!("carousel disabled");
+        }
+        _ => log::warn!("usage: carousel <on <seconds>|off>"),
+    }
+}
@@ -0,0 +1,26 @@
+//! Runtime memory/health reporting, surfaced through the console.
+
+use esp_idf_svc::sys::{esp_get_free_heap_size, heap_caps_get_free_size, MALLOC_CAP_SPIRAM};
+
+#[derive(Debug, Clone, Copy)]
+pub struct HeapReport {
+    pub free_heap_bytes: u32,
+    pub free_psram_bytes: u32,
+}
+
+pub fn heap_report() -> HeapReport {
+    HeapReport {
+        free_heap_bytes: unsafe { esp_get_free_heap_size() },
+        free_psram_bytes: unsafe { heap_caps_get_free_size(MALLOC_CAP_SPIRAM) } as u32,
+    }
+}
+
+impl std::fmt::Display for HeapReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "heap: {} bytes free, psram: {} bytes free",
+            self.free_heap_bytes, self.free_psram_bytes
+        )
+    }
+}
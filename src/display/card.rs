@@ -0,0 +1,106 @@
+//! A bordered "card" with a title row and body rows, used wherever
+//! a view needs to box off a chunk of text (forecast cards, HVAC summary)
+//! instead of re-deriving title/body placement per call site.
+
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::Text;
+
+use super::Framebuffer;
+
+/// Colors for a card's border, background, title, and body text.
+#[derive(Debug, Clone, Copy)]
+pub struct CardTheme {
+    pub border: Rgb565,
+    pub background: Rgb565,
+    pub title_color: Rgb565,
+    pub body_color: Rgb565,
+}
+
+impl Default for CardTheme {
+    fn default() -> Self {
+        Self {
+            border: Rgb565::WHITE,
+            background: Rgb565::BLACK,
+            title_color: Rgb565::WHITE,
+            body_color: Rgb565::new(20, 40, 20),
+        }
+    }
+}
+
+/// Header row height reserved for the title.
+const TITLE_HEIGHT: i32 = 14;
+/// Height of one body text row.
+const ROW_HEIGHT: i32 = 12;
+/// Inner padding on each side of the card.
+const PADDING: i32 = 4;
+
+/// How many body rows fit under the title within a card of `rect_height`
+/// pixels, given the fixed title/row heights and padding above.
+pub fn max_body_rows(rect_height: i32) -> usize {
+    let available = rect_height - TITLE_HEIGHT - PADDING;
+    if available <= 0 {
+        return 0;
+    }
+    (available / ROW_HEIGHT).max(0) as usize
+}
+
+/// Draws a card: a filled, bordered box containing `title` in a header row
+/// and as many of `rows` as fit, clipped to the card's bounds (rather than
+/// overflowing onto whatever's drawn after it).
+pub fn draw_titled_card<D: Framebuffer>(
+    fb: &mut D,
+    rect: Rectangle,
+    title: &str,
+    rows: &[&str],
+    theme: CardTheme,
+) -> Result<(), D::Error> {
+    rect.into_styled(PrimitiveStyle::with_fill(theme.background))
+        .draw(fb)?;
+    rect.into_styled(PrimitiveStyle::with_stroke(theme.border, 1))
+        .draw(fb)?;
+
+    let title_style = MonoTextStyle::new(&FONT_6X10, theme.title_color);
+    Text::new(
+        title,
+        rect.top_left + Point::new(PADDING, PADDING + FONT_6X10.character_size.height as i32),
+        title_style,
+    )
+    .draw(fb)?;
+
+    let body_style = MonoTextStyle::new(&FONT_6X10, theme.body_color);
+    let visible_rows = max_body_rows(rect.size.height as i32).min(rows.len());
+    for (i, row) in rows.iter().take(visible_rows).enumerate() {
+        let y = PADDING + TITLE_HEIGHT + (i as i32 + 1) * ROW_HEIGHT;
+        Text::new(row, rect.top_left + Point::new(PADDING, y), body_style).draw(fb)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_room_for_body_rows_in_a_short_card() {
+        assert_eq!(max_body_rows(TITLE_HEIGHT), 0);
+    }
+
+    #[test]
+    fn taller_card_fits_more_rows() {
+        let short = max_body_rows(40);
+        let tall = max_body_rows(100);
+        assert!(tall > short, "expected {tall} > {short}");
+    }
+
+    #[test]
+    fn row_count_matches_exact_multiple_of_row_height() {
+        let rows = 3;
+        let height = TITLE_HEIGHT + PADDING + rows * ROW_HEIGHT;
+        assert_eq!(max_body_rows(height), rows as usize);
+    }
+}
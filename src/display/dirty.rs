@@ -0,0 +1,74 @@
+//! Dirty-region tracking: a view records which rects it actually drew
+//! into, so the caller driving the panel can flush just those regions
+//! instead of the whole 320x480 frame every tick.
+
+use embedded_graphics::primitives::Rectangle;
+
+/// Rects touched by one view's `draw` call this frame. Views `mark` each
+/// region as they draw it (header, graph, summary, ...); the caller reads
+/// [`Self::rects`] afterward to decide what to transmit to the panel.
+#[derive(Debug, Clone, Default)]
+pub struct DirtyRegions {
+    rects: Vec<Rectangle>,
+}
+
+impl DirtyRegions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `rect` as touched. Zero-size rects (a region that collapsed
+    /// to nothing, e.g. no graph area in a degenerate layout) are dropped
+    /// rather than recorded, so they don't show up as phantom flush work.
+    pub fn mark(&mut self, rect: Rectangle) {
+        if rect.size.width > 0 && rect.size.height > 0 {
+            self.rects.push(rect);
+        }
+    }
+
+    pub fn rects(&self) -> &[Rectangle] {
+        &self.rects
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::prelude::*;
+
+    #[test]
+    fn a_view_touching_only_the_summary_marks_only_that_rect_dirty() {
+        let header = Rectangle::new(Point::zero(), Size::new(320, 14));
+        let summary = Rectangle::new(Point::new(0, 446), Size::new(320, 20));
+
+        // Stand-in for a view that only redraws its summary row this frame.
+        let mut dirty = DirtyRegions::new();
+        dirty.mark(summary);
+
+        assert_eq!(dirty.rects(), &[summary]);
+        assert!(!dirty.rects().contains(&header));
+    }
+
+    #[test]
+    fn zero_size_rects_are_not_recorded() {
+        let mut dirty = DirtyRegions::new();
+        dirty.mark(Rectangle::new(Point::zero(), Size::new(0, 20)));
+        assert!(dirty.is_empty());
+    }
+
+    #[test]
+    fn multiple_marks_accumulate_in_order() {
+        let header = Rectangle::new(Point::zero(), Size::new(320, 14));
+        let graph = Rectangle::new(Point::new(10, 14), Size::new(300, 400));
+
+        let mut dirty = DirtyRegions::new();
+        dirty.mark(header);
+        dirty.mark(graph);
+
+        assert_eq!(dirty.rects(), &[header, graph]);
+    }
+}
@@ -0,0 +1,92 @@
+//! Golden-image test harness: renders a view into a [`MockFramebuffer`] and
+//! compares it against a reference image saved under `testdata/golden/`, so
+//! layout regressions in a view show up as a failing test instead of only
+//! being noticed on hardware.
+//!
+//! Goldens are plain `width,height` headers followed by raw RGB565 bytes
+//! (little-endian), one file per case. A missing golden is treated as "not
+//! yet recorded" and is written on the spot rather than failing the test —
+//! the usual first-run bootstrap for this kind of test. To deliberately
+//! re-record a golden after an intentional layout change, delete the file
+//! under `testdata/golden/` (or set `UPDATE_GOLDEN=1`) and re-run.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+
+use super::mock::MockFramebuffer;
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata/golden")
+        .join(format!("{name}.raw"))
+}
+
+fn encode(fb: &MockFramebuffer, width: u32, height: u32) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + (width * height * 2) as usize);
+    bytes.extend_from_slice(&width.to_le_bytes());
+    bytes.extend_from_slice(&height.to_le_bytes());
+    for y in 0..height as i32 {
+        for x in 0..width as i32 {
+            let color = fb.pixel(Point::new(x, y)).unwrap_or(Rgb565::BLACK);
+            bytes.extend_from_slice(&color.into_storage().to_le_bytes());
+        }
+    }
+    bytes
+}
+
+/// Number of pixels allowed to differ before a golden comparison fails.
+/// Zero, so [`assert_matches_golden`] requires an exact match; use
+/// [`assert_matches_golden_with_tolerance`] to allow some drift.
+const DEFAULT_TOLERANCE_PIXELS: usize = 0;
+
+/// Renders `fb` and compares it against the golden named `name`, recording
+/// a fresh golden if one doesn't exist yet (or `UPDATE_GOLDEN=1` is set).
+/// Fails if more than `tolerance_pixels` pixels differ from the recorded
+/// golden.
+pub fn assert_matches_golden(name: &str, fb: &MockFramebuffer, width: u32, height: u32) {
+    assert_matches_golden_with_tolerance(name, fb, width, height, DEFAULT_TOLERANCE_PIXELS)
+}
+
+pub fn assert_matches_golden_with_tolerance(
+    name: &str,
+    fb: &MockFramebuffer,
+    width: u32,
+    height: u32,
+    tolerance_pixels: usize,
+) {
+    let path = golden_path(name);
+    let actual = encode(fb, width, height);
+    let force_update = std::env::var("UPDATE_GOLDEN").as_deref() == Ok("1");
+
+    if force_update || !path.exists() {
+        std::fs::create_dir_all(path.parent().unwrap()).expect("create testdata/golden");
+        let mut file = std::fs::File::create(&path).expect("write golden file");
+        file.write_all(&actual).expect("write golden bytes");
+        return;
+    }
+
+    let expected = std::fs::read(&path).expect("read golden file");
+    let diff_count = diff_pixel_count(&expected, &actual);
+    assert!(
+        diff_count <= tolerance_pixels,
+        "golden '{name}' differs in {diff_count} pixel(s) (tolerance {tolerance_pixels}); \
+         delete {path:?} or set UPDATE_GOLDEN=1 if this is an intentional layout change"
+    );
+}
+
+/// Counts differing RGB565 pixels between two encoded buffers. Buffers with
+/// mismatched headers (e.g. a golden recorded at a different screen size)
+/// count every pixel as different.
+fn diff_pixel_count(expected: &[u8], actual: &[u8]) -> usize {
+    if expected.len() != actual.len() || expected.len() < 8 {
+        return expected.len().max(actual.len());
+    }
+    expected[8..]
+        .chunks_exact(2)
+        .zip(actual[8..].chunks_exact(2))
+        .filter(|(a, b)| a != b)
+        .count()
+}
@@ -0,0 +1,137 @@
+//! Small layout helpers the views repeat a lot: label/value rows (e.g.
+//! "Pressure" left-aligned, "1013.2 hPa" right-aligned in the same row)
+//! and horizontal fill bars (precip probability, backlight level,
+//! volume, snooze remaining), so each view stops hand-computing its own
+//! x positions and fill widths.
+
+use std::fmt::Write;
+
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::Text;
+
+use super::text_metrics::text_width;
+use super::Framebuffer;
+
+/// Formats `args` into `scratch` (clearing it first) and returns the
+/// written text, so a label redrawn every frame reuses one `String`
+/// instead of `format!` allocating a fresh one each time. Call with
+/// `format_args!(...)`, the same as `format!`.
+pub fn format_into<'a>(scratch: &'a mut String, args: std::fmt::Arguments) -> &'a str {
+    scratch.clear();
+    let _ = scratch.write_fmt(args);
+    scratch.as_str()
+}
+
+/// X positions for a label (left-aligned at the row's left edge) and a
+/// value (right-aligned within `width`) rendered in `font`.
+pub fn kv_positions(width: i32, value: &str, font: &MonoFont) -> (i32, i32) {
+    let label_x = 0;
+    let value_x = (width - text_width(value, font)).max(label_x);
+    (label_x, value_x)
+}
+
+/// Draws a label/value row: `label` left-aligned, `value` right-aligned
+/// within `width`, both at `y` and relative to `origin`.
+pub fn draw_kv<D: Framebuffer>(
+    fb: &mut D,
+    origin: Point,
+    width: i32,
+    y: i32,
+    label: &str,
+    value: &str,
+    font: &MonoFont,
+    color: Rgb565,
+) -> Result<(), D::Error> {
+    let (label_x, value_x) = kv_positions(width, value, font);
+    let style = MonoTextStyle::new(font, color);
+    Text::new(label, origin + Point::new(label_x, y), style).draw(fb)?;
+    Text::new(value, origin + Point::new(value_x, y), style).draw(fb)?;
+    Ok(())
+}
+
+/// Width in pixels of the filled portion of a bar `rect` wide, for
+/// `fraction` clamped to `0.0..=1.0`.
+pub fn bar_fill_width(rect: Rectangle, fraction: f32) -> u32 {
+    let fraction = fraction.clamp(0.0, 1.0);
+    (rect.size.width as f32 * fraction).round() as u32
+}
+
+/// Draws a horizontal fill bar: a bordered `rect` with `fraction` of its
+/// width filled from the left, used for precip probability, backlight
+/// level, volume, and snooze-remaining indicators.
+pub fn draw_bar<D: Framebuffer>(
+    fb: &mut D,
+    rect: Rectangle,
+    fraction: f32,
+    fill: Rgb565,
+    bg: Rgb565,
+    border: Rgb565,
+) -> Result<(), D::Error> {
+    rect.into_styled(PrimitiveStyle::with_fill(bg)).draw(fb)?;
+    let fill_width = bar_fill_width(rect, fraction);
+    if fill_width > 0 {
+        Rectangle::new(rect.top_left, Size::new(fill_width, rect.size.height))
+            .into_styled(PrimitiveStyle::with_fill(fill))
+            .draw(fb)?;
+    }
+    rect.into_styled(PrimitiveStyle::with_stroke(border, 1))
+        .draw(fb)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::ascii::FONT_7X13;
+
+    #[test]
+    fn value_right_aligns_within_width() {
+        let width = 100;
+        let (label_x, value_x) = kv_positions(width, "1013.2", &FONT_7X13);
+        assert_eq!(label_x, 0);
+        assert_eq!(value_x, width - text_width("1013.2", &FONT_7X13));
+    }
+
+    #[test]
+    fn value_wider_than_row_clamps_to_label_edge() {
+        let width = 5;
+        let (label_x, value_x) = kv_positions(width, "way too long for this row", &FONT_7X13);
+        assert_eq!(value_x, label_x);
+    }
+
+    #[test]
+    fn bar_fill_width_at_zero_half_and_full() {
+        let rect = Rectangle::new(Point::zero(), Size::new(100, 10));
+        assert_eq!(bar_fill_width(rect, 0.0), 0);
+        assert_eq!(bar_fill_width(rect, 0.5), 50);
+        assert_eq!(bar_fill_width(rect, 1.0), 100);
+    }
+
+    #[test]
+    fn bar_fill_width_clamps_out_of_range_fractions() {
+        let rect = Rectangle::new(Point::zero(), Size::new(100, 10));
+        assert_eq!(bar_fill_width(rect, -0.5), 0);
+        assert_eq!(bar_fill_width(rect, 1.5), 100);
+    }
+
+    #[test]
+    fn format_into_matches_the_equivalent_format_macro() {
+        let latest = 1013.2_f32;
+        let trend_glyph = "/\\";
+        let expected = format!("{latest:.1} hPa {trend_glyph}");
+
+        let mut scratch = String::new();
+        let written = format_into(&mut scratch, format_args!("{latest:.1} hPa {trend_glyph}"));
+        assert_eq!(written, expected);
+    }
+
+    #[test]
+    fn format_into_reuses_the_buffer_rather_than_appending() {
+        let mut scratch = String::from("stale data from a previous frame");
+        let written = format_into(&mut scratch, format_args!("fresh"));
+        assert_eq!(written, "fresh");
+    }
+}
@@ -0,0 +1,26 @@
+//! Framebuffer type alias and the view modules that render onto it.
+
+pub mod card;
+pub mod dirty;
+#[cfg(test)]
+pub mod golden;
+pub mod layout;
+#[cfg(test)]
+pub mod mock;
+pub mod navigation;
+pub mod page;
+pub mod regions;
+pub mod status_bar;
+pub mod tester;
+pub mod text_metrics;
+pub mod transition;
+pub mod views;
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+
+/// Everything a view draws into. Blanket-implemented for any Rgb565
+/// `DrawTarget`, so views are generic over `D: Framebuffer` rather than
+/// naming the concrete LCD driver type.
+pub trait Framebuffer: DrawTarget<Color = Rgb565> {}
+impl<T> Framebuffer for T where T: DrawTarget<Color = Rgb565> {}
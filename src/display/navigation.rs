@@ -0,0 +1,62 @@
+//! Page-switch logic, indexing into the user's configured (and possibly
+//! reordered/trimmed) page list rather than a fixed enum order.
+
+use super::page::Page;
+
+/// The page that follows `current` in `enabled`, wrapping around. Falls
+/// back to `current` unchanged if `enabled` is empty (nothing to navigate
+/// to), and to the first enabled page if `current` isn't in the list
+/// (e.g. it was just disabled).
+pub fn next_page(current: Page, enabled: &[Page]) -> Page {
+    if enabled.is_empty() {
+        return current;
+    }
+    match enabled.iter().position(|p| *p == current) {
+        Some(idx) => enabled[(idx + 1) % enabled.len()],
+        None => enabled[0],
+    }
+}
+
+/// The page that precedes `current` in `enabled`, wrapping around. Same
+/// empty/missing fallback behavior as [`next_page`].
+pub fn prev_page(current: Page, enabled: &[Page]) -> Page {
+    if enabled.is_empty() {
+        return current;
+    }
+    match enabled.iter().position(|p| *p == current) {
+        Some(idx) => enabled[(idx + enabled.len() - 1) % enabled.len()],
+        None => enabled[0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_over_custom_order() {
+        let order = [Page::Pressure, Page::Weather, Page::Settings];
+        assert_eq!(next_page(Page::Pressure, &order), Page::Weather);
+        assert_eq!(next_page(Page::Weather, &order), Page::Settings);
+        assert_eq!(next_page(Page::Settings, &order), Page::Pressure);
+    }
+
+    #[test]
+    fn prev_wraps_over_custom_order() {
+        let order = [Page::Pressure, Page::Weather, Page::Settings];
+        assert_eq!(prev_page(Page::Pressure, &order), Page::Settings);
+        assert_eq!(prev_page(Page::Settings, &order), Page::Weather);
+    }
+
+    #[test]
+    fn empty_list_is_a_no_op() {
+        assert_eq!(next_page(Page::Hvac, &[]), Page::Hvac);
+        assert_eq!(prev_page(Page::Hvac, &[]), Page::Hvac);
+    }
+
+    #[test]
+    fn missing_current_falls_back_to_first() {
+        let order = [Page::Weather, Page::Settings];
+        assert_eq!(next_page(Page::Hvac, &order), Page::Weather);
+    }
+}
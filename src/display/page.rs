@@ -0,0 +1,18 @@
+//! The set of swipeable pages and their fixed cycle order.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Weather,
+    Hvac,
+    Pressure,
+    Warnings,
+    Settings,
+}
+
+pub const ALL: [Page; 5] = [
+    Page::Weather,
+    Page::Hvac,
+    Page::Pressure,
+    Page::Warnings,
+    Page::Settings,
+];
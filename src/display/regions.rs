@@ -0,0 +1,102 @@
+//! Orientation-aware region rectangles (header, graph, summary, hint), so
+//! views query `ScreenLayout::new(orientation).graph_rect()` instead of
+//! hard-coding pixel positions for one orientation.
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::touch::Orientation;
+
+use super::status_bar;
+
+const SUMMARY_HEIGHT: u32 = 20;
+const HINT_HEIGHT: u32 = 14;
+const GRAPH_MARGIN: i32 = 10;
+
+/// The panel is 320x480 native (portrait); landscape swaps the axes.
+pub fn screen_size(orientation: Orientation) -> Size {
+    match orientation {
+        Orientation::Portrait | Orientation::PortraitFlipped => Size::new(320, 480),
+        Orientation::Landscape | Orientation::LandscapeFlipped => Size::new(480, 320),
+    }
+}
+
+/// Computed region rectangles for one orientation, stacked top to bottom:
+/// status bar header, then the main graph area, a summary row, and a hint
+/// row pinned to the bottom.
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenLayout {
+    header: Rectangle,
+    graph: Rectangle,
+    summary: Rectangle,
+    hint: Rectangle,
+}
+
+impl ScreenLayout {
+    pub fn new(orientation: Orientation) -> Self {
+        let size = screen_size(orientation);
+        let header = Rectangle::new(Point::zero(), Size::new(size.width, status_bar::HEIGHT));
+
+        let hint_top = size.height as i32 - HINT_HEIGHT as i32;
+        let hint = Rectangle::new(Point::new(0, hint_top), Size::new(size.width, HINT_HEIGHT));
+
+        let summary_top = hint_top - SUMMARY_HEIGHT as i32;
+        let summary = Rectangle::new(Point::new(0, summary_top), Size::new(size.width, SUMMARY_HEIGHT));
+
+        let graph_top = header.size.height as i32;
+        let graph_height = (summary_top - graph_top).max(0) as u32;
+        let graph_width = size.width.saturating_sub(2 * GRAPH_MARGIN as u32);
+        let graph = Rectangle::new(Point::new(GRAPH_MARGIN, graph_top), Size::new(graph_width, graph_height));
+
+        Self {
+            header,
+            graph,
+            summary,
+            hint,
+        }
+    }
+
+    pub fn header_rect(&self) -> Rectangle {
+        self.header
+    }
+
+    pub fn graph_rect(&self) -> Rectangle {
+        self.graph
+    }
+
+    pub fn summary_rect(&self) -> Rectangle {
+        self.summary
+    }
+
+    pub fn hint_rect(&self) -> Rectangle {
+        self.hint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portrait_regions_stack_within_the_taller_screen() {
+        let layout = ScreenLayout::new(Orientation::Portrait);
+        assert_eq!(layout.header_rect().size, Size::new(320, status_bar::HEIGHT));
+        assert_eq!(layout.hint_rect().top_left.y, 480 - HINT_HEIGHT as i32);
+        assert!(layout.graph_rect().size.height > 0);
+    }
+
+    #[test]
+    fn landscape_regions_use_the_wider_screen() {
+        let layout = ScreenLayout::new(Orientation::Landscape);
+        assert_eq!(layout.header_rect().size, Size::new(480, status_bar::HEIGHT));
+        assert_eq!(layout.hint_rect().top_left.y, 320 - HINT_HEIGHT as i32);
+        assert!(layout.graph_rect().size.height > 0);
+    }
+
+    #[test]
+    fn graph_rect_sits_below_the_header_and_above_the_summary() {
+        let layout = ScreenLayout::new(Orientation::Portrait);
+        assert_eq!(layout.graph_rect().top_left.y, layout.header_rect().size.height as i32);
+        assert!(layout.graph_rect().top_left.y + (layout.graph_rect().size.height as i32) <= layout.summary_rect().top_left.y);
+    }
+}
@@ -0,0 +1,119 @@
+//! A thin persistent bar drawn by every view: clock, Wi-Fi strength, and a
+//! per-sensor ok/fail indicator. Views reserve `HEIGHT` pixels at the top
+//! of the screen for this.
+
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, PrimitiveStyle};
+use embedded_graphics::text::Text;
+
+use crate::display::Framebuffer;
+use crate::power::SupplyLevel;
+use crate::state::AppState;
+
+/// Pixel height reserved at the top of the screen; views should start
+/// drawing their own content below this.
+pub const HEIGHT: i32 = 14;
+
+/// Maps an RSSI reading (dBm, typically -90..-30) to a 0-4 signal-bar
+/// count, matching the thresholds most phone status bars use.
+pub fn wifi_bars(rssi_dbm: i8) -> u8 {
+    match rssi_dbm {
+        r if r >= -50 => 4,
+        r if r >= -60 => 3,
+        r if r >= -70 => 2,
+        r if r >= -80 => 1,
+        _ => 0,
+    }
+}
+
+fn draw_sensor_dot<D: Framebuffer>(fb: &mut D, x: i32, ok: bool) -> Result<(), D::Error> {
+    let color = if ok { Rgb565::GREEN } else { Rgb565::RED };
+    Circle::new(Point::new(x, 3), 5)
+        .into_styled(PrimitiveStyle::with_fill(color))
+        .draw(fb)
+}
+
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &AppState) -> Result<(), D::Error> {
+    let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+
+    if let Some(unix_time_s) = state.unix_time_s {
+        let clock = crate::time::format_hh_mm(unix_time_s, state.utc_offset_s);
+        Text::new(&clock, Point::new(4, 10), style).draw(fb)?;
+    }
+
+    if let Some(rssi) = state.wifi_rssi_dbm {
+        let bars = wifi_bars(rssi);
+        Text::new(&format!("wifi:{bars}"), Point::new(200, 10), style).draw(fb)?;
+    }
+
+    draw_sensor_dot(fb, 260, state.sensor_health.bme_ok)?;
+    draw_sensor_dot(fb, 275, state.sensor_health.imu_ok)?;
+    draw_sensor_dot(fb, 290, state.sensor_health.touch_ok)?;
+
+    if state.supply_level != SupplyLevel::Ok {
+        let label = if state.supply_level == SupplyLevel::Critical {
+            "LOW PWR!"
+        } else {
+            "pwr low"
+        };
+        Text::new(label, Point::new(60, 10), style).draw(fb)?;
+    }
+
+    // An alert below `severity_filter`'s floor doesn't raise the warning
+    // view, so this badge is the only on-screen sign it's active.
+    let severity_filter = state.config.settings.severity_filter;
+    if state
+        .active_alerts
+        .iter()
+        .any(|alert| !severity_filter.passes(alert.kind))
+    {
+        Text::new("alert", Point::new(110, 10), style).draw(fb)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strong_signal_is_four_bars() {
+        assert_eq!(wifi_bars(-40), 4);
+    }
+
+    #[test]
+    fn boundary_values_round_down() {
+        assert_eq!(wifi_bars(-50), 4);
+        assert_eq!(wifi_bars(-51), 3);
+        assert_eq!(wifi_bars(-60), 3);
+        assert_eq!(wifi_bars(-61), 2);
+    }
+
+    #[test]
+    fn very_weak_signal_is_zero_bars() {
+        assert_eq!(wifi_bars(-90), 0);
+    }
+
+    #[test]
+    fn a_filtered_out_alert_still_lights_the_badge_column() {
+        use crate::alerts::{build_synthetic, AlertKind};
+        use crate::config::AppConfig;
+        use crate::display::mock::MockFramebuffer;
+
+        let mut fb = MockFramebuffer::new(320, 20);
+        let mut state = AppState::new(AppConfig::default());
+        state.config.settings.severity_filter.min_severity = AlertKind::Watch;
+        state
+            .active_alerts
+            .push(build_synthetic(AlertKind::Advisory, "Suppressed advisory", 0));
+
+        draw(&mut fb, &state).unwrap();
+
+        let lit = (0..12).any(|dx| fb.pixel(Point::new(110 + dx, 10)).is_some_and(|c| c != Rgb565::BLACK));
+        assert!(lit, "expected the alert badge to be drawn");
+    }
+}
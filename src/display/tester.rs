@@ -0,0 +1,265 @@
+//! Factory/bring-up display test: cycles through a fixed set of patterns
+//! so a panel can be visually checked for dead pixels, color channel
+//! swaps, and timing issues.
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+
+use crate::display::Framebuffer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    SolidRed,
+    SolidGreen,
+    SolidBlue,
+    ColorBars,
+    Checkerboard,
+    /// Animated: a full-screen hue sweep, advanced by the `frame` passed to
+    /// [`draw_pattern`]. Useful for spotting ghosting/smearing on the
+    /// panel that static patterns won't reveal.
+    RainbowSweep,
+}
+
+pub const ALL_PATTERNS: [Pattern; 6] = [
+    Pattern::SolidRed,
+    Pattern::SolidGreen,
+    Pattern::SolidBlue,
+    Pattern::ColorBars,
+    Pattern::Checkerboard,
+    Pattern::RainbowSweep,
+];
+
+/// Number of in-flight DMA transfers allowed before we block waiting for
+/// one to complete. Deeper queues smooth out tearing at the cost of more
+/// PSRAM held by in-flight buffers.
+pub const DEFAULT_QUEUE_DEPTH: usize = 2;
+
+/// Runs every pattern in order, timing each draw call so a slow pattern
+/// (e.g. the checkerboard's many small fills) shows up clearly in logs.
+pub fn run_all<D: Framebuffer>(
+    fb: &mut D,
+    screen: Size,
+) -> Result<Vec<(Pattern, std::time::Duration)>, D::Error> {
+    run_from(fb, screen, 0)
+}
+
+/// Runs the patterns starting at `start_index`, so a test session
+/// interrupted partway through (e.g. by a reset) can pick up where it left
+/// off instead of re-running patterns already confirmed good. An
+/// out-of-range index runs nothing rather than panicking.
+pub fn run_from<D: Framebuffer>(
+    fb: &mut D,
+    screen: Size,
+    start_index: usize,
+) -> Result<Vec<(Pattern, std::time::Duration)>, D::Error> {
+    let mut timings = Vec::new();
+    for pattern in ALL_PATTERNS.iter().skip(start_index) {
+        let start = std::time::Instant::now();
+        draw_pattern(fb, *pattern, screen, 0)?;
+        let elapsed = start.elapsed();
+        log::info!("display test {pattern:?}: {elapsed:?}");
+        timings.push((*pattern, elapsed));
+    }
+    Ok(timings)
+}
+
+/// Index-controllable test session state, driven by the `goto`/`rerun`/
+/// `skip` console commands read at the tester's interactive prompt.
+/// Automatic progression (advancing one index per completed test) is still
+/// the default.
+pub struct TesterState {
+    current_index: usize,
+}
+
+impl TesterState {
+    pub fn new() -> Self {
+        Self { current_index: 0 }
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    pub fn current_pattern(&self) -> Pattern {
+        ALL_PATTERNS[self.current_index]
+    }
+
+    /// Advances to the next test, stopping at the last one. Only the index
+    /// moves; no result is recorded here.
+    pub fn advance(&mut self) {
+        self.current_index = (self.current_index + 1).min(ALL_PATTERNS.len() - 1);
+    }
+
+    /// `skip`: advances without recording a result. Index-wise this is the
+    /// same move as `advance`; the distinction matters to the caller that
+    /// logs results, not to the index itself.
+    pub fn skip(&mut self) {
+        self.advance();
+    }
+
+    /// `rerun`: repeats the current test (a no-op on the index).
+    pub fn rerun(&mut self) {}
+
+    /// `goto <n>`: jumps to test `n`, clamped to the valid range so a
+    /// typo'd index doesn't panic on array access.
+    pub fn goto(&mut self, index: usize) {
+        self.current_index = index.min(ALL_PATTERNS.len() - 1);
+    }
+}
+
+impl Default for TesterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod rainbow_tests {
+    use super::*;
+
+    #[test]
+    fn hue_zero_is_pure_red() {
+        assert_eq!(hue_to_rgb565(0), Rgb565::new(31, 0, 0));
+    }
+
+    #[test]
+    fn hue_wraps_back_near_red() {
+        let near_wrap = hue_to_rgb565(254);
+        // Near the end of the wheel we should be back in magenta/red
+        // territory, i.e. red channel at or near max.
+        assert!(near_wrap.r() >= 24);
+    }
+}
+
+#[cfg(test)]
+mod tester_state_tests {
+    use super::*;
+
+    #[test]
+    fn goto_within_range_is_exact() {
+        let mut s = TesterState::new();
+        s.goto(2);
+        assert_eq!(s.current_index(), 2);
+    }
+
+    #[test]
+    fn goto_past_end_clamps_to_last_test() {
+        let mut s = TesterState::new();
+        s.goto(999);
+        assert_eq!(s.current_index(), ALL_PATTERNS.len() - 1);
+    }
+
+    #[test]
+    fn advance_stops_at_last_test() {
+        let mut s = TesterState::new();
+        for _ in 0..ALL_PATTERNS.len() + 3 {
+            s.advance();
+        }
+        assert_eq!(s.current_index(), ALL_PATTERNS.len() - 1);
+    }
+
+    #[test]
+    fn rerun_does_not_change_index() {
+        let mut s = TesterState::new();
+        s.goto(1);
+        s.rerun();
+        assert_eq!(s.current_index(), 1);
+    }
+}
+
+/// `frame` is only consumed by animated patterns (currently just
+/// `RainbowSweep`); static patterns ignore it.
+pub fn draw_pattern<D: Framebuffer>(
+    fb: &mut D,
+    pattern: Pattern,
+    screen: Size,
+    frame: u32,
+) -> Result<(), D::Error> {
+    match pattern {
+        Pattern::SolidRed => fill(fb, screen, Rgb565::RED),
+        Pattern::SolidGreen => fill(fb, screen, Rgb565::GREEN),
+        Pattern::SolidBlue => fill(fb, screen, Rgb565::BLUE),
+        Pattern::ColorBars => draw_color_bars(fb, screen),
+        Pattern::Checkerboard => draw_checkerboard(fb, screen),
+        Pattern::RainbowSweep => draw_rainbow_sweep(fb, screen, frame),
+    }
+}
+
+/// Hue (0-255, wrapping) to an approximate RGB565 color, full saturation
+/// and value.
+fn hue_to_rgb565(hue: u8) -> Rgb565 {
+    let region = hue / 43;
+    let remainder = (hue % 43) * 6;
+    let (r, g, b) = match region {
+        0 => (255, remainder, 0),
+        1 => (255 - remainder, 255, 0),
+        2 => (0, 255, remainder),
+        3 => (0, 255 - remainder, 255),
+        4 => (remainder, 0, 255),
+        _ => (255, 0, 255 - remainder),
+    };
+    Rgb565::new(r >> 3, g >> 2, b >> 3)
+}
+
+fn draw_rainbow_sweep<D: Framebuffer>(fb: &mut D, screen: Size, frame: u32) -> Result<(), D::Error> {
+    const STRIPE_WIDTH: u32 = 10;
+    let stripes = screen.width / STRIPE_WIDTH + 1;
+    for i in 0..stripes {
+        let hue = ((i * 8 + frame) % 256) as u8;
+        Rectangle::new(
+            Point::new((i * STRIPE_WIDTH) as i32, 0),
+            Size::new(STRIPE_WIDTH, screen.height),
+        )
+        .into_styled(PrimitiveStyle::with_fill(hue_to_rgb565(hue)))
+        .draw(fb)?;
+    }
+    Ok(())
+}
+
+fn fill<D: Framebuffer>(fb: &mut D, screen: Size, color: Rgb565) -> Result<(), D::Error> {
+    Rectangle::new(Point::zero(), screen)
+        .into_styled(PrimitiveStyle::with_fill(color))
+        .draw(fb)
+}
+
+fn draw_color_bars<D: Framebuffer>(fb: &mut D, screen: Size) -> Result<(), D::Error> {
+    let bars = [
+        Rgb565::WHITE,
+        Rgb565::new(31, 63, 0), // yellow
+        Rgb565::CYAN,
+        Rgb565::GREEN,
+        Rgb565::new(31, 0, 31), // magenta
+        Rgb565::RED,
+        Rgb565::BLUE,
+    ];
+    let bar_width = (screen.width / bars.len() as u32).max(1);
+    for (i, color) in bars.iter().enumerate() {
+        Rectangle::new(
+            Point::new((i as u32 * bar_width) as i32, 0),
+            Size::new(bar_width, screen.height),
+        )
+        .into_styled(PrimitiveStyle::with_fill(*color))
+        .draw(fb)?;
+    }
+    Ok(())
+}
+
+fn draw_checkerboard<D: Framebuffer>(fb: &mut D, screen: Size) -> Result<(), D::Error> {
+    const CELL: u32 = 20;
+    let cols = screen.width / CELL + 1;
+    let rows = screen.height / CELL + 1;
+    for row in 0..rows {
+        for col in 0..cols {
+            if (row + col) % 2 == 0 {
+                Rectangle::new(
+                    Point::new((col * CELL) as i32, (row * CELL) as i32),
+                    Size::new(CELL, CELL),
+                )
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::WHITE))
+                .draw(fb)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Text width helpers derived from a `MonoFont`'s actual glyph metrics,
+//! rather than a hard-coded "~Npx per char" guess that only holds for one
+//! font.
+
+use embedded_graphics::mono_font::MonoFont;
+
+/// Pixel width of `text` rendered in `font`, including inter-character
+/// spacing but not any leading/trailing margin.
+pub fn text_width(text: &str, font: &MonoFont) -> i32 {
+    if text.is_empty() {
+        return 0;
+    }
+    let chars = text.chars().count() as i32;
+    let advance = font.character_size.width as i32 + font.character_spacing as i32;
+    chars * advance - font.character_spacing as i32
+}
+
+/// How many characters of `font` fit within `width_px` without exceeding
+/// it, for wrapping/truncation decisions.
+pub fn max_chars_for_width(width_px: i32, font: &MonoFont) -> usize {
+    let advance = font.character_size.width as i32 + font.character_spacing as i32;
+    if advance <= 0 || width_px <= 0 {
+        return 0;
+    }
+    // Inverse of `text_width`: width_px >= n*advance - spacing
+    // => n <= (width_px + spacing) / advance
+    ((width_px + font.character_spacing as i32) / advance).max(0) as usize
+}
+
+/// Truncates `s` to at most `max_bytes`, snapping down to the nearest
+/// preceding `char` boundary rather than panicking if `max_bytes` falls
+/// inside a multibyte character. NWS alert text isn't guaranteed ASCII, so
+/// a plain `&s[..max_bytes]` is a crash waiting to happen.
+pub fn truncate_at_char_boundary(s: &str, max_bytes: usize) -> &str {
+    if max_bytes >= s.len() {
+        return s;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_7X13};
+
+    #[test]
+    fn width_scales_with_character_count() {
+        assert_eq!(text_width("", &FONT_7X13), 0);
+        assert_eq!(text_width("A", &FONT_7X13), FONT_7X13.character_size.width as i32);
+        assert_eq!(
+            text_width("AAAA", &FONT_7X13),
+            4 * FONT_7X13.character_size.width as i32
+        );
+    }
+
+    #[test]
+    fn width_differs_between_fonts() {
+        let small = text_width("hello", &FONT_6X10);
+        let large = text_width("hello", &FONT_7X13);
+        assert!(large > small, "expected {large} > {small}");
+    }
+
+    #[test]
+    fn max_chars_matches_text_width_inverse() {
+        for font in [&FONT_6X10, &FONT_7X13] {
+            let n = 5;
+            let width = text_width(&"x".repeat(n), font);
+            assert!(max_chars_for_width(width, font) >= n);
+        }
+    }
+
+    #[test]
+    fn zero_width_fits_nothing() {
+        assert_eq!(max_chars_for_width(0, &FONT_7X13), 0);
+    }
+
+    #[test]
+    fn truncate_leaves_short_strings_untouched() {
+        assert_eq!(truncate_at_char_boundary("hello", 10), "hello");
+    }
+
+    #[test]
+    fn truncate_mid_multibyte_char_does_not_panic() {
+        // "café" is 5 bytes ('é' is 2 bytes); byte index 4 falls inside it.
+        let s = "café";
+        assert_eq!(truncate_at_char_boundary(s, 4), "caf");
+    }
+
+    #[test]
+    fn truncate_on_a_clean_boundary_keeps_the_full_prefix() {
+        let s = "café";
+        assert_eq!(truncate_at_char_boundary(s, 3), "caf");
+    }
+}
@@ -0,0 +1,117 @@
+//! Horizontal slide transition for a swipe-committed page change. This
+//! module only computes the per-frame pixel offsets and easing; the
+//! dispatcher is responsible for drawing the outgoing and incoming pages
+//! shifted by those offsets for the duration of the transition.
+
+/// How long a slide transition takes from commit to settled.
+pub const TRANSITION_DURATION_MS: u64 = 200;
+
+/// Ease-out cubic: starts fast and settles into place, rather than
+/// decelerating right up to a hard stop.
+pub fn ease_out_cubic(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Which way the incoming page slides in from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideDirection {
+    /// Incoming page slides in from the right (forward navigation).
+    Left,
+    /// Incoming page slides in from the left (backward navigation).
+    Right,
+}
+
+/// Tracks one in-flight page transition, committed at `started_at_ms`.
+#[derive(Debug, Clone, Copy)]
+pub struct PageTransition {
+    pub direction: SlideDirection,
+    pub started_at_ms: u64,
+}
+
+impl PageTransition {
+    pub fn new(direction: SlideDirection, started_at_ms: u64) -> Self {
+        Self {
+            direction,
+            started_at_ms,
+        }
+    }
+
+    /// 0.0 right at commit, 1.0 once [`TRANSITION_DURATION_MS`] has
+    /// elapsed.
+    pub fn progress(&self, now_ms: u64) -> f32 {
+        let elapsed = now_ms.saturating_sub(self.started_at_ms) as f32;
+        (elapsed / TRANSITION_DURATION_MS as f32).clamp(0.0, 1.0)
+    }
+
+    pub fn is_done(&self, now_ms: u64) -> bool {
+        self.progress(now_ms) >= 1.0
+    }
+
+    fn entry_offset(&self, screen_width: u32) -> i32 {
+        match self.direction {
+            SlideDirection::Left => screen_width as i32,
+            SlideDirection::Right => -(screen_width as i32),
+        }
+    }
+
+    /// Pixel x-offset for the incoming page: starts fully off-screen
+    /// (`entry_offset`) and eases to 0.
+    pub fn incoming_offset_x(&self, now_ms: u64, screen_width: u32) -> i32 {
+        let eased = ease_out_cubic(self.progress(now_ms));
+        (self.entry_offset(screen_width) as f32 * (1.0 - eased)).round() as i32
+    }
+
+    /// Pixel x-offset for the outgoing page: starts at 0 and eases fully
+    /// off-screen in the same direction the incoming page arrives from.
+    pub fn outgoing_offset_x(&self, now_ms: u64, screen_width: u32) -> i32 {
+        self.incoming_offset_x(now_ms, screen_width) - self.entry_offset(screen_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_is_zero_at_start_and_one_at_end() {
+        assert_eq!(ease_out_cubic(0.0), 0.0);
+        assert_eq!(ease_out_cubic(1.0), 1.0);
+    }
+
+    #[test]
+    fn easing_is_monotonically_increasing() {
+        let samples: Vec<f32> = (0..=10).map(|i| ease_out_cubic(i as f32 / 10.0)).collect();
+        for pair in samples.windows(2) {
+            assert!(pair[1] >= pair[0], "{:?} not increasing", samples);
+        }
+    }
+
+    #[test]
+    fn incoming_offset_starts_off_screen_and_settles_to_zero() {
+        let t = PageTransition::new(SlideDirection::Left, 0);
+        assert_eq!(t.incoming_offset_x(0, 320), 320);
+        assert_eq!(t.incoming_offset_x(TRANSITION_DURATION_MS, 320), 0);
+    }
+
+    #[test]
+    fn outgoing_offset_starts_at_zero_and_slides_fully_off() {
+        let t = PageTransition::new(SlideDirection::Left, 0);
+        assert_eq!(t.outgoing_offset_x(0, 320), 0);
+        assert_eq!(t.outgoing_offset_x(TRANSITION_DURATION_MS, 320), -320);
+    }
+
+    #[test]
+    fn right_direction_slides_from_the_opposite_side() {
+        let t = PageTransition::new(SlideDirection::Right, 0);
+        assert_eq!(t.incoming_offset_x(0, 320), -320);
+        assert_eq!(t.outgoing_offset_x(TRANSITION_DURATION_MS, 320), 320);
+    }
+
+    #[test]
+    fn transition_is_done_only_after_its_duration() {
+        let t = PageTransition::new(SlideDirection::Left, 1_000);
+        assert!(!t.is_done(1_000 + TRANSITION_DURATION_MS - 1));
+        assert!(t.is_done(1_000 + TRANSITION_DURATION_MS));
+    }
+}
@@ -0,0 +1,112 @@
+//! Startup splash: a checklist of boot steps (time sync, first weather
+//! fetch) shown while they're still in progress, before the normal
+//! swipeable UI takes over.
+
+use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_7X13};
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::text::Text;
+
+use crate::display::Framebuffer;
+
+/// One step of the boot sequence, in the order `main` is expected to clear
+/// them. Derived `Ord` relies on this declaration order, so keep it
+/// matching [`SEQUENCE`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BootStage {
+    ConnectingWifi,
+    SyncingTime,
+    FetchingWeather,
+    Ready,
+}
+
+pub const SEQUENCE: [BootStage; 4] = [
+    BootStage::ConnectingWifi,
+    BootStage::SyncingTime,
+    BootStage::FetchingWeather,
+    BootStage::Ready,
+];
+
+impl BootStage {
+    pub fn label(self) -> &'static str {
+        match self {
+            BootStage::ConnectingWifi => "Connecting to Wi-Fi",
+            BootStage::SyncingTime => "Syncing time",
+            BootStage::FetchingWeather => "Fetching weather",
+            BootStage::Ready => "Ready",
+        }
+    }
+
+    fn index(self) -> usize {
+        SEQUENCE
+            .iter()
+            .position(|s| *s == self)
+            .expect("every BootStage variant appears in SEQUENCE")
+    }
+
+    /// Fraction of the boot sequence completed once this stage is
+    /// reached, in `0.0..=1.0`.
+    pub fn progress_fraction(self) -> f32 {
+        self.index() as f32 / (SEQUENCE.len() - 1) as f32
+    }
+}
+
+impl Default for BootStage {
+    fn default() -> Self {
+        BootStage::ConnectingWifi
+    }
+}
+
+const TITLE: &str = "Starting up...";
+const ROW_HEIGHT: i32 = 16;
+
+pub fn draw<D: Framebuffer>(fb: &mut D, stage: BootStage) -> Result<(), D::Error> {
+    let title_style = MonoTextStyle::new(&FONT_7X13, Rgb565::WHITE);
+    Text::new(TITLE, Point::new(10, 20), title_style).draw(fb)?;
+
+    let item_style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+    for (i, step) in SEQUENCE.iter().filter(|s| **s != BootStage::Ready).enumerate() {
+        let marker = if *step < stage {
+            "[x]"
+        } else if *step == stage {
+            "[*]"
+        } else {
+            "[ ]"
+        };
+        let line = format!("{marker} {}", step.label());
+        Text::new(&line, Point::new(10, 40 + i as i32 * ROW_HEIGHT), item_style).draw(fb)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::mock::MockFramebuffer;
+
+    #[test]
+    fn labels_are_distinct_and_human_readable() {
+        assert_eq!(BootStage::ConnectingWifi.label(), "Connecting to Wi-Fi");
+        assert_eq!(BootStage::SyncingTime.label(), "Syncing time");
+        assert_eq!(BootStage::FetchingWeather.label(), "Fetching weather");
+        assert_eq!(BootStage::Ready.label(), "Ready");
+    }
+
+    #[test]
+    fn progress_fraction_spans_zero_to_one() {
+        assert_eq!(BootStage::ConnectingWifi.progress_fraction(), 0.0);
+        assert_eq!(BootStage::Ready.progress_fraction(), 1.0);
+        assert!(BootStage::SyncingTime.progress_fraction() > 0.0);
+        assert!(BootStage::SyncingTime.progress_fraction() < BootStage::FetchingWeather.progress_fraction());
+    }
+
+    #[test]
+    fn draw_does_not_error_at_any_stage() {
+        let mut fb = MockFramebuffer::new(320, 480);
+        for stage in SEQUENCE {
+            draw(&mut fb, stage).unwrap();
+        }
+    }
+}
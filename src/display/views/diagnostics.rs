@@ -0,0 +1,110 @@
+//! Read-only diagnostics overlay: sensor health, last HTTP error, free
+//! heap, Wi-Fi RSSI, and uptime. Not part of the normal swipe cycle (see
+//! [`crate::display::page::Page`]) — reached via a long-press gesture
+//! (see [`crate::touch::is_long_press`]) and left again the same way.
+
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::text::Text;
+
+use crate::display::Framebuffer;
+use crate::state::AppState;
+
+const ROW_HEIGHT: i32 = 16;
+const LEFT: i32 = 10;
+const TOP: i32 = 20;
+
+/// Formats a millisecond uptime as `{h}h {m}m {s}s` (unpadded), dropping the hours
+/// field once it's been up less than an hour (matches the compactness of
+/// [`crate::alerts::timing::format_relative`]).
+fn format_uptime(now_ms: u64) -> String {
+    let total_secs = now_ms / 1_000;
+    let hours = total_secs / 3_600;
+    let mins = (total_secs % 3_600) / 60;
+    let secs = total_secs % 60;
+    if hours > 0 {
+        format!("{hours}h {mins}m {secs}s")
+    } else {
+        format!("{mins}m {secs}s")
+    }
+}
+
+fn ok_label(ok: bool) -> &'static str {
+    if ok {
+        "ok"
+    } else {
+        "FAIL"
+    }
+}
+
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &AppState) -> Result<(), D::Error> {
+    let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+    let mut row = 0;
+    let mut line = |fb: &mut D, text: &str| -> Result<(), D::Error> {
+        Text::new(text, Point::new(LEFT, TOP + row * ROW_HEIGHT), style).draw(fb)?;
+        row += 1;
+        Ok(())
+    };
+
+    line(fb, "Diagnostics")?;
+    line(fb, &format!("uptime: {}", format_uptime(state.now_ms)))?;
+    line(
+        fb,
+        &format!(
+            "bme: {}  imu: {}  touch: {}",
+            ok_label(state.sensor_health.bme_ok),
+            ok_label(state.sensor_health.imu_ok),
+            ok_label(state.sensor_health.touch_ok)
+        ),
+    )?;
+    line(
+        fb,
+        &match state.wifi_rssi_dbm {
+            Some(rssi) => format!("wifi rssi: {rssi}dBm"),
+            None => "wifi rssi: --".to_string(),
+        },
+    )?;
+    line(
+        fb,
+        &match &state.last_heap_report {
+            Some(report) => format!("{report}"),
+            None => "heap: --".to_string(),
+        },
+    )?;
+    line(
+        fb,
+        &match &state.last_http_error {
+            Some(err) => format!("last HTTP error: {err}"),
+            None => "last HTTP error: none".to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::display::mock::MockFramebuffer;
+
+    #[test]
+    fn format_uptime_drops_the_hours_field_under_an_hour() {
+        assert_eq!(format_uptime(65_000), "1m 5s");
+    }
+
+    #[test]
+    fn format_uptime_includes_hours_once_past_an_hour() {
+        assert_eq!(format_uptime(3_725_000), "1h 2m 5s");
+    }
+
+    #[test]
+    fn draw_renders_without_error() {
+        let mut state = AppState::new(AppConfig::default());
+        state.now_ms = 90_000;
+        let mut fb = MockFramebuffer::new(320, 480);
+        draw(&mut fb, &state).unwrap();
+    }
+}
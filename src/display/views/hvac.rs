@@ -0,0 +1,125 @@
+//! HVAC page: a timeline strip showing heat/cool/idle over the timeline's
+//! history window (24h at the default 5-minute sample period).
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::text::Text;
+
+use crate::display::{status_bar, Framebuffer};
+use crate::hvac::{self, HvacMode};
+use crate::state::AppState;
+use crate::thresholds;
+
+/// Hunting is declared if the system flips mode more than this many times
+/// within the window below.
+const HUNTING_MAX_TRANSITIONS: usize = 4;
+const HUNTING_WINDOW_MS: u64 = 30 * 60 * 1_000;
+
+const STRIP_TOP: i32 = 60;
+const STRIP_HEIGHT: u32 = 24;
+const STRIP_LEFT: i32 = 10;
+const STRIP_WIDTH: u32 = 300;
+
+/// Stacked heat/cool/idle runtime-proportion bar, drawn below the timeline
+/// strip and hunting label.
+const PROPORTION_BAR_TOP: i32 = STRIP_TOP + 70;
+const PROPORTION_BAR_HEIGHT: u32 = 16;
+const HEATING_COLOR: Rgb565 = Rgb565::new(31, 40, 0); // orange
+const COOLING_COLOR: Rgb565 = Rgb565::BLUE;
+const IDLE_COLOR: Rgb565 = Rgb565::new(12, 24, 12); // gray
+
+/// Shown in place of the timeline strip until there's enough history to be
+/// meaningful (see [`thresholds::HVAC_MIN_HISTORY_MINUTES`]).
+const NO_DATA_TEXT: &str = "Collecting data...";
+
+fn mode_color(mode: HvacMode) -> Rgb565 {
+    match mode {
+        HvacMode::Idle => Rgb565::new(8, 16, 8),
+        HvacMode::Heating => Rgb565::RED,
+        HvacMode::Cooling => Rgb565::BLUE,
+    }
+}
+
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &AppState) -> Result<(), D::Error> {
+    status_bar::draw(fb, state)?;
+
+    let samples: heapless::Vec<_, { crate::hvac::TIMELINE_CAPACITY }> =
+        state.hvac_timeline.iter().copied().collect();
+    let min_samples = thresholds::min_samples_for_minutes(
+        thresholds::HVAC_MIN_HISTORY_MINUTES,
+        state.hvac_timeline.sample_period_secs(),
+    );
+    if samples.len() < min_samples {
+        let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+        Text::new(NO_DATA_TEXT, Point::new(STRIP_LEFT, STRIP_TOP), style).draw(fb)?;
+        return Ok(());
+    }
+
+    let col_width = (STRIP_WIDTH as usize / samples.len()).max(1) as u32;
+    for (i, sample) in samples.iter().enumerate() {
+        let x = STRIP_LEFT + (i as u32 * col_width) as i32;
+        Rectangle::new(Point::new(x, STRIP_TOP), Size::new(col_width, STRIP_HEIGHT))
+            .into_styled(PrimitiveStyle::with_fill(mode_color(sample.mode)))
+            .draw(fb)?;
+    }
+
+    if hvac::is_hunting(&samples, HUNTING_WINDOW_MS, HUNTING_MAX_TRANSITIONS) {
+        let style = MonoTextStyle::new(&FONT_6X10, Rgb565::YELLOW);
+        Text::new("hunting detected", Point::new(STRIP_LEFT, STRIP_TOP + 50), style).draw(fb)?;
+    }
+
+    draw_runtime_proportion_bar(fb, &samples)?;
+
+    Ok(())
+}
+
+/// Draws a single bar stacking the timeline's heating/cooling/idle share,
+/// which reads faster at a glance than the three "N samples" text lines it
+/// replaces.
+fn draw_runtime_proportion_bar<D: Framebuffer>(
+    fb: &mut D,
+    samples: &[hvac::HvacSample],
+) -> Result<(), D::Error> {
+    let stats = hvac::compute_stats(samples);
+    let (heating_width, cooling_width, idle_width) = stats.segment_widths(STRIP_WIDTH);
+
+    let mut x = STRIP_LEFT;
+    for (width, color) in [
+        (heating_width, HEATING_COLOR),
+        (cooling_width, COOLING_COLOR),
+        (idle_width, IDLE_COLOR),
+    ] {
+        if width > 0 {
+            Rectangle::new(Point::new(x, PROPORTION_BAR_TOP), Size::new(width, PROPORTION_BAR_HEIGHT))
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(fb)?;
+        }
+        x += width as i32;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::display::golden::assert_matches_golden;
+    use crate::display::mock::MockFramebuffer;
+
+    const SCREEN_WIDTH: u32 = 320;
+    const SCREEN_HEIGHT: u32 = 170;
+
+    #[test]
+    fn collecting_data_state_matches_golden() {
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let state = AppState::new(AppConfig::default());
+
+        draw(&mut fb, &state).unwrap();
+
+        assert_matches_golden("hvac_collecting_data", &fb, SCREEN_WIDTH, SCREEN_HEIGHT);
+    }
+}
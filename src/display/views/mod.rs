@@ -0,0 +1,6 @@
+pub mod boot;
+pub mod diagnostics;
+pub mod hvac;
+pub mod pressure;
+pub mod warnings;
+pub mod weather;
@@ -0,0 +1,246 @@
+//! Pressure history page: trend arrow plus the graph. Reads only from
+//! `PressureHistory`, which `pressure::sampler` keeps filled on its own
+//! cadence — this view doesn't touch `bme`/`weather` directly.
+
+use embedded_graphics::mono_font::ascii::FONT_7X13;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::text::Text;
+
+use crate::display::dirty::DirtyRegions;
+use crate::display::layout::{draw_kv, format_into};
+use crate::display::regions::ScreenLayout;
+use crate::display::{status_bar, Framebuffer};
+use crate::graph;
+use crate::pressure::{sampler, Trend};
+use crate::state::AppState;
+use crate::thresholds;
+
+const ROW_WIDTH: i32 = 300;
+
+/// Color for the optional outdoor-temperature overlay line, distinct from
+/// the pressure line's color so the two series stay visually separable.
+const OUTDOOR_TEMP_OVERLAY_COLOR: Rgb565 = Rgb565::MAGENTA;
+
+/// Color for the touch cursor and its value tooltip (see
+/// [`crate::graph::index_from_x`]).
+const CURSOR_COLOR: Rgb565 = Rgb565::YELLOW;
+
+/// Shown in place of the trend/graph until there's enough history to be
+/// meaningful (see [`thresholds::PRESSURE_MIN_HISTORY_MINUTES`]).
+const NO_DATA_TEXT: &str = "Collecting data...";
+
+fn trend_glyph(trend: Trend) -> &'static str {
+    match trend {
+        Trend::Rising => "/\\",
+        Trend::Falling => "\\/",
+        Trend::Steady => "--",
+    }
+}
+
+/// Takes `state` mutably (unlike the other views) so the downsampled
+/// series can be written into `state.pressure_graph_values`/
+/// `pressure_graph_timestamps` and reused next frame instead of
+/// allocating fresh `Vec`s on every redraw.
+///
+/// Records which regions it actually drew into `dirty`, so a future panel
+/// flush can transmit just those rects instead of the whole frame.
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &mut AppState, dirty: &mut DirtyRegions) -> Result<(), D::Error> {
+    status_bar::draw(fb, state)?;
+    let layout = ScreenLayout::new(state.config.screen_orientation);
+    dirty.mark(layout.header_rect());
+
+    let min_samples = thresholds::min_samples_for_minutes(
+        thresholds::PRESSURE_MIN_HISTORY_MINUTES,
+        sampler::SAMPLE_PERIOD_SECS,
+    );
+    if state.pressure_history.len() < min_samples {
+        let style = MonoTextStyle::new(&FONT_7X13, Rgb565::WHITE);
+        Text::new(NO_DATA_TEXT, Point::new(10, 40), style).draw(fb)?;
+        return Ok(());
+    }
+
+    if let Some(latest) = state.pressure_history.latest() {
+        let trend = state.pressure_history.trend();
+        let value = format_into(
+            &mut state.pressure_label_scratch,
+            format_args!("{latest:.1} hPa {}", trend_glyph(trend)),
+        );
+        draw_kv(
+            fb,
+            Point::new(10, 40),
+            ROW_WIDTH,
+            0,
+            "Pressure",
+            value,
+            &FONT_7X13,
+            Rgb565::WHITE,
+        )?;
+    }
+
+    if let Some(stats) = state.pressure_history.bme_stats() {
+        let value = format_into(&mut state.pressure_label_scratch, format_args!("sigma {:.1} hPa", stats.std_dev));
+        draw_kv(
+            fb,
+            Point::new(10, 40),
+            ROW_WIDTH,
+            16,
+            "Volatility",
+            value,
+            &FONT_7X13,
+            Rgb565::WHITE,
+        )?;
+    }
+
+    let smoothing_enabled = state.config.settings.graph_smoothing_enabled;
+    let AppState {
+        pressure_history,
+        pressure_graph_values,
+        pressure_graph_timestamps,
+        ..
+    } = state;
+    let graph_width = layout.graph_rect().size.width as usize;
+    if smoothing_enabled {
+        pressure_history.smoothed_downsampled_into(graph_width, pressure_graph_values, pressure_graph_timestamps);
+    } else {
+        pressure_history.downsampled_into(graph_width, pressure_graph_values, pressure_graph_timestamps);
+    }
+
+    graph::draw_line_graph(
+        fb,
+        layout.graph_rect(),
+        pressure_graph_values.as_slice(),
+        state.config.pressure_graph_style,
+        sampler::SAMPLE_PERIOD_SECS,
+        Some(pressure_graph_timestamps.as_slice()),
+    )?;
+
+    if state.config.show_outdoor_temp_overlay {
+        let temps: heapless::Vec<Option<f32>, { crate::pressure::HISTORY_CAPACITY }> =
+            state.pressure_history.outdoor_temp_values().collect();
+        graph::draw_overlay_line(fb, layout.graph_rect(), &temps, OUTDOOR_TEMP_OVERLAY_COLOR, 1)?;
+    }
+
+    if let Some(touch_x) = state.graph_touch_x {
+        let local_x = touch_x - layout.graph_rect().top_left.x;
+        if let Some(idx) = graph::index_from_x(local_x, layout.graph_rect().size.width, pressure_graph_values.len()) {
+            let cursor_x = layout.graph_rect().top_left.x + local_x;
+            Line::new(
+                Point::new(cursor_x, layout.graph_rect().top_left.y),
+                Point::new(cursor_x, layout.graph_rect().top_left.y + layout.graph_rect().size.height as i32),
+            )
+            .into_styled(PrimitiveStyle::with_stroke(CURSOR_COLOR, 1))
+            .draw(fb)?;
+
+            let value = pressure_graph_values[idx];
+            let text = format_into(&mut state.graph_tooltip_scratch, format_args!("{value:.1} hPa"));
+            let style = MonoTextStyle::new(&FONT_7X13, CURSOR_COLOR);
+            Text::new(text, Point::new(cursor_x + 4, layout.graph_rect().top_left.y + 12), style).draw(fb)?;
+        }
+    }
+    dirty.mark(layout.graph_rect());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::display::mock::MockFramebuffer;
+
+    const SCREEN_WIDTH: u32 = 320;
+    const SCREEN_HEIGHT: u32 = 480;
+
+    #[test]
+    fn collecting_data_only_marks_the_header_dirty() {
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut state = AppState::new(AppConfig::default());
+        let mut dirty = DirtyRegions::new();
+
+        draw(&mut fb, &mut state, &mut dirty).unwrap();
+
+        let layout = ScreenLayout::new(state.config.screen_orientation);
+        assert_eq!(dirty.rects(), &[layout.header_rect()]);
+    }
+
+    #[test]
+    fn a_populated_history_also_marks_the_graph_dirty() {
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut state = AppState::new(AppConfig::default());
+        let min_samples =
+            thresholds::min_samples_for_minutes(thresholds::PRESSURE_MIN_HISTORY_MINUTES, sampler::SAMPLE_PERIOD_SECS);
+        for i in 0..min_samples {
+            state.pressure_history.push(1013.0, None, i as u64 * 600_000);
+        }
+        let mut dirty = DirtyRegions::new();
+
+        draw(&mut fb, &mut state, &mut dirty).unwrap();
+
+        let layout = ScreenLayout::new(state.config.screen_orientation);
+        assert_eq!(dirty.rects(), &[layout.header_rect(), layout.graph_rect()]);
+    }
+
+    #[test]
+    fn the_graph_smoothing_setting_selects_which_series_method_the_view_calls() {
+        let min_samples =
+            thresholds::min_samples_for_minutes(thresholds::PRESSURE_MIN_HISTORY_MINUTES, sampler::SAMPLE_PERIOD_SECS);
+        let spiky_history = |state: &mut AppState| {
+            for i in 0..min_samples + 20 {
+                let hpa = if i == min_samples + 10 { 1030.0 } else { 1000.0 };
+                state.pressure_history.push(hpa, None, i as u64 * 600_000);
+            }
+        };
+
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut raw_state = AppState::new(AppConfig::default());
+        spiky_history(&mut raw_state);
+        raw_state.config.settings.graph_smoothing_enabled = false;
+        draw(&mut fb, &mut raw_state, &mut DirtyRegions::new()).unwrap();
+
+        let mut smoothed_state = AppState::new(AppConfig::default());
+        spiky_history(&mut smoothed_state);
+        smoothed_state.config.settings.graph_smoothing_enabled = true;
+        draw(&mut fb, &mut smoothed_state, &mut DirtyRegions::new()).unwrap();
+
+        assert_ne!(raw_state.pressure_graph_values, smoothed_state.pressure_graph_values);
+    }
+
+    #[test]
+    fn a_touch_on_the_graph_draws_a_cursor_pixel_at_that_column() {
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut state = AppState::new(AppConfig::default());
+        let min_samples =
+            thresholds::min_samples_for_minutes(thresholds::PRESSURE_MIN_HISTORY_MINUTES, sampler::SAMPLE_PERIOD_SECS);
+        for i in 0..min_samples {
+            state.pressure_history.push(1013.0, None, i as u64 * 600_000);
+        }
+        let layout = ScreenLayout::new(state.config.screen_orientation);
+        let touch_x = layout.graph_rect().top_left.x + layout.graph_rect().size.width as i32 / 2;
+        state.graph_touch_x = Some(touch_x);
+
+        draw(&mut fb, &mut state, &mut DirtyRegions::new()).unwrap();
+
+        assert_eq!(fb.pixel(Point::new(touch_x, layout.graph_rect().top_left.y)), Some(CURSOR_COLOR));
+    }
+
+    #[test]
+    fn a_touch_outside_the_graph_draws_no_cursor() {
+        let mut fb = MockFramebuffer::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        let mut state = AppState::new(AppConfig::default());
+        let min_samples =
+            thresholds::min_samples_for_minutes(thresholds::PRESSURE_MIN_HISTORY_MINUTES, sampler::SAMPLE_PERIOD_SECS);
+        for i in 0..min_samples {
+            state.pressure_history.push(1013.0, None, i as u64 * 600_000);
+        }
+        state.graph_touch_x = Some(-1000);
+
+        // Should draw without panicking and leave the graph's top-left
+        // corner as the plain background color, not the cursor color.
+        draw(&mut fb, &mut state, &mut DirtyRegions::new()).unwrap();
+        let layout = ScreenLayout::new(state.config.screen_orientation);
+        assert_ne!(fb.pixel(layout.graph_rect().top_left), Some(CURSOR_COLOR));
+    }
+}
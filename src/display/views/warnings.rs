@@ -0,0 +1,404 @@
+//! Warnings/alerts page. No alert poller fills `state.active_alerts`; the
+//! only alerts it holds are synthetic ones pushed by the `testalert`
+//! console command, which the severity-themed rendering below draws. The silence
+//! button ([`handle_tap`]) is likewise ready for a real touch point as
+//! soon as a dispatcher routes one to it — no such dispatcher exists yet
+//! (see `main`'s loop, which doesn't poll the touch controller at all).
+
+use embedded_graphics::mono_font::ascii::FONT_7X13;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Alignment, Text};
+
+use crate::alerts::{timing, AlertKind};
+use crate::display::text_metrics::{text_width, truncate_at_char_boundary};
+use crate::display::{status_bar, Framebuffer};
+use crate::state::AppState;
+use crate::touch::ButtonId;
+
+const HIGHLIGHT_COLOR: Rgb565 = Rgb565::YELLOW;
+/// Gap between words when drawing a highlighted description line
+/// word-by-word.
+const WORD_GAP_PX: i32 = 4;
+
+/// Max bytes of the headline/description shown on one page, past which
+/// text is truncated (char-boundary-safe — NWS text isn't guaranteed
+/// ASCII).
+const MAX_HEADLINE_BYTES: usize = 60;
+const MAX_DESCRIPTION_BYTES: usize = 200;
+
+const NO_ALERTS_TEXT: &str = "No active alerts";
+
+/// How long the new-alert attention pulse runs before settling on the
+/// plain severity background, mirroring
+/// [`crate::display::transition::TRANSITION_DURATION_MS`]'s role for page
+/// slides.
+pub const PULSE_DURATION_MS: u64 = 3_000;
+
+/// Interpolates `bg_color` toward `accent_color` and back along a
+/// half-sine as `elapsed_ms` runs from `0` to [`PULSE_DURATION_MS`], so a
+/// newly-arrived alert's background catches the eye for a few seconds
+/// before settling. Returns `bg_color` unchanged once `elapsed_ms` reaches
+/// the duration.
+pub fn pulse_background(bg_color: Rgb565, accent_color: Rgb565, elapsed_ms: u64) -> Rgb565 {
+    if elapsed_ms >= PULSE_DURATION_MS {
+        return bg_color;
+    }
+    let phase = elapsed_ms as f32 / PULSE_DURATION_MS as f32 * std::f32::consts::PI;
+    lerp_color(bg_color, accent_color, phase.sin())
+}
+
+fn lerp_channel(from: u8, to: u8, t: f32) -> u8 {
+    (from as f32 + (to as f32 - from as f32) * t).round() as u8
+}
+
+fn lerp_color(from: Rgb565, to: Rgb565, t: f32) -> Rgb565 {
+    Rgb565::new(
+        lerp_channel(from.r(), to.r(), t),
+        lerp_channel(from.g(), to.g(), t),
+        lerp_channel(from.b(), to.b(), t),
+    )
+}
+
+/// (title, accent, background) colors for a given severity, so the whole
+/// page — background fill, separator, badge text — stays consistent per
+/// severity instead of a single fixed `BG_WARNING` regardless of kind.
+fn alert_colors(kind: AlertKind) -> (Rgb565, Rgb565, Rgb565) {
+    match kind {
+        AlertKind::Advisory => (Rgb565::BLACK, Rgb565::YELLOW, Rgb565::new(20, 20, 0)),
+        AlertKind::Watch => (Rgb565::WHITE, Rgb565::new(31, 40, 0), Rgb565::new(12, 10, 0)),
+        AlertKind::Warning => (Rgb565::WHITE, Rgb565::RED, Rgb565::new(16, 0, 0)),
+    }
+}
+
+/// One run of a description line, either plain or matching one of
+/// the configured highlight keywords (case-sensitive, whole-word — NWS
+/// keywords like "TORNADO" are always upper-case in source text).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub highlighted: bool,
+}
+
+/// Splits `line` on whitespace into styled spans, marking each word that
+/// exactly matches a configured keyword. Used to draw the
+/// description word-by-word instead of with a single `Text::new` per
+/// line, so individual words can pick up a highlight color.
+pub fn tokenize_line<'a>(line: &'a str, keywords: &[String]) -> Vec<Span<'a>> {
+    line.split_whitespace()
+        .map(|word| Span {
+            text: word,
+            highlighted: keywords.iter().any(|kw| kw == word),
+        })
+        .collect()
+}
+
+/// Registered in [`AppState::warnings_buttons`] for the tap that mutes the
+/// currently-displayed alert's tone (see [`handle_tap`]).
+const SILENCE_BUTTON_ID: ButtonId = 1;
+
+const SILENCE_BUTTON_SIZE: Size = Size::new(70, 22);
+/// Gap from the screen's right/top edges to the silence button, clearing
+/// [`status_bar::HEIGHT`] so it doesn't overlap the status bar.
+const SILENCE_BUTTON_MARGIN_PX: i32 = 6;
+
+/// Where the silence button sits: top-right, just below the status bar, so
+/// it's reachable without obscuring the headline/description text below.
+fn silence_button_rect(screen: Size) -> Rectangle {
+    Rectangle::new(
+        Point::new(
+            screen.width as i32 - SILENCE_BUTTON_SIZE.width as i32 - SILENCE_BUTTON_MARGIN_PX,
+            status_bar::HEIGHT + SILENCE_BUTTON_MARGIN_PX,
+        ),
+        SILENCE_BUTTON_SIZE,
+    )
+}
+
+fn draw_silence_button<D: Framebuffer>(fb: &mut D, screen: Size, muted: bool) -> Result<(), D::Error> {
+    let rect = silence_button_rect(screen);
+    let (border_color, label) = if muted {
+        (Rgb565::new(15, 30, 15), "MUTED")
+    } else {
+        (Rgb565::WHITE, "SILENCE")
+    };
+    rect.into_styled(PrimitiveStyle::with_stroke(border_color, 1)).draw(fb)?;
+    let style = MonoTextStyle::new(&FONT_7X13, border_color);
+    Text::with_alignment(label, rect.center(), style, Alignment::Center).draw(fb)?;
+    Ok(())
+}
+
+/// Hit-tests `point` (screen coordinates) against the buttons registered by
+/// the most recent [`draw`], muting the active alert's tone via
+/// [`AppState::alert_silence`] if it lands on the silence button. Returns
+/// whether the tap landed on a registered button, so a future touch
+/// dispatcher knows not to fall through to e.g. a page-change gesture.
+pub fn handle_tap(point: Point, state: &mut AppState) -> bool {
+    match state.warnings_buttons.hit_test(point) {
+        Some(SILENCE_BUTTON_ID) => {
+            state.alert_silence.mute(state.now_ms);
+            true
+        }
+        Some(_) => true,
+        None => false,
+    }
+}
+
+fn draw_no_alerts<D: Framebuffer>(fb: &mut D, screen: Size) -> Result<(), D::Error> {
+    let center = Rectangle::new(Point::zero(), screen).center();
+    let style = MonoTextStyle::new(&FONT_7X13, Rgb565::WHITE);
+    Text::with_alignment(NO_ALERTS_TEXT, center, style, Alignment::Center).draw(fb)?;
+    Ok(())
+}
+
+/// Draws the (truncated, single-line) description, word-by-word if keyword
+/// highlighting is enabled (so individual words can pick up
+/// [`HIGHLIGHT_COLOR`]), or as a single run otherwise.
+fn draw_description_line<D: Framebuffer>(
+    fb: &mut D,
+    line: &str,
+    origin: Point,
+    body_color: Rgb565,
+    keywords: &[String],
+) -> Result<(), D::Error> {
+    if keywords.is_empty() {
+        let style = MonoTextStyle::new(&FONT_7X13, body_color);
+        Text::new(line, origin, style).draw(fb)?;
+        return Ok(());
+    }
+
+    let mut x = origin.x;
+    for span in tokenize_line(line, keywords) {
+        let color = if span.highlighted { HIGHLIGHT_COLOR } else { body_color };
+        let style = MonoTextStyle::new(&FONT_7X13, color);
+        Text::new(span.text, Point::new(x, origin.y), style).draw(fb)?;
+        x += text_width(span.text, &FONT_7X13) + WORD_GAP_PX;
+    }
+    Ok(())
+}
+
+fn draw_alert<D: Framebuffer>(
+    fb: &mut D,
+    kind: AlertKind,
+    headline: &str,
+    description: &str,
+    onset_at_ms: Option<u64>,
+    now_ms: u64,
+    keywords: &[String],
+    screen: Size,
+    pulse_elapsed_ms: Option<u64>,
+) -> Result<(), D::Error> {
+    let (title_color, accent_color, bg_color) = alert_colors(kind);
+    let bg_color = match pulse_elapsed_ms {
+        Some(elapsed) => pulse_background(bg_color, accent_color, elapsed),
+        None => bg_color,
+    };
+    Rectangle::new(Point::zero(), screen)
+        .into_styled(PrimitiveStyle::with_fill(bg_color))
+        .draw(fb)?;
+    Rectangle::new(Point::new(0, 0), Size::new(screen.width, 3))
+        .into_styled(PrimitiveStyle::with_fill(accent_color))
+        .draw(fb)?;
+
+    let headline = truncate_at_char_boundary(headline, MAX_HEADLINE_BYTES);
+    let description = truncate_at_char_boundary(description, MAX_DESCRIPTION_BYTES);
+
+    let style = MonoTextStyle::new(&FONT_7X13, title_color);
+    Text::new(headline, Point::new(10, 30), style).draw(fb)?;
+
+    let onset_line = timing::onset_line(now_ms, onset_at_ms);
+    Text::new(&onset_line, Point::new(10, 48), style).draw(fb)?;
+
+    draw_description_line(fb, description, Point::new(10, 66), title_color, keywords)?;
+    Ok(())
+}
+
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &mut AppState, screen: Size) -> Result<(), D::Error> {
+    status_bar::draw(fb, state)?;
+    state.warnings_buttons.clear();
+    let keywords: &[String] = if state.config.settings.alert_display.keyword_highlight_enabled {
+        &state.config.settings.alert_display.highlight_keywords
+    } else {
+        &[]
+    };
+    let pulse_elapsed_ms = if state.config.animations_enabled {
+        state
+            .alert_pulse_started_ms
+            .map(|started_at| state.now_ms.saturating_sub(started_at))
+    } else {
+        None
+    };
+    let severity_filter = state.config.settings.severity_filter;
+    match state
+        .active_alerts
+        .first()
+        .filter(|alert| severity_filter.passes(alert.kind))
+    {
+        Some(alert) => {
+            draw_alert(
+                fb,
+                alert.kind,
+                &alert.headline,
+                &alert.description,
+                alert.onset_at_ms,
+                state.now_ms,
+                keywords,
+                screen,
+                pulse_elapsed_ms,
+            )?;
+            draw_silence_button(fb, screen, state.alert_silence.is_muted())?;
+            state.warnings_buttons.register(silence_button_rect(screen), SILENCE_BUTTON_ID);
+        }
+        None => draw_no_alerts(fb, screen)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::display::mock::MockFramebuffer;
+
+    #[test]
+    fn no_alerts_fallback_lights_a_center_pixel() {
+        let screen = Size::new(320, 170);
+        let mut fb = MockFramebuffer::new(screen.width, screen.height);
+        let mut state = AppState::new(AppConfig::default());
+
+        draw(&mut fb, &mut state, screen).unwrap();
+
+        // The fallback text is centered on the screen; some pixel in a
+        // small box around the center should be lit by a glyph rather than
+        // left as background.
+        let center = Rectangle::new(Point::zero(), screen).center();
+        let lit = (-6..=6).flat_map(|dx| (-6..=6).map(move |dy| (dx, dy))).any(|(dx, dy)| {
+            fb.pixel(center + Point::new(dx, dy))
+                .is_some_and(|c| c != Rgb565::BLACK)
+        });
+        assert!(lit, "expected a lit pixel near the center of the no-alerts fallback");
+    }
+
+    #[test]
+    fn an_alert_below_the_severity_floor_falls_back_to_the_no_alerts_screen() {
+        let screen = Size::new(320, 170);
+        let mut fb = MockFramebuffer::new(screen.width, screen.height);
+        let mut state = AppState::new(AppConfig::default());
+        state.config.settings.severity_filter.min_severity = AlertKind::Watch;
+        state.active_alerts.push(crate::alerts::build_synthetic(
+            AlertKind::Advisory,
+            "Suppressed advisory",
+            0,
+        ));
+
+        draw(&mut fb, &mut state, screen).unwrap();
+
+        let center = Rectangle::new(Point::zero(), screen).center();
+        let lit = (-6..=6).flat_map(|dx| (-6..=6).map(move |dy| (dx, dy))).any(|(dx, dy)| {
+            fb.pixel(center + Point::new(dx, dy))
+                .is_some_and(|c| c != Rgb565::BLACK)
+        });
+        assert!(lit, "expected the no-alerts fallback, not the advisory page");
+    }
+
+    #[test]
+    fn warning_and_advisory_use_distinct_backgrounds() {
+        let (_, _, warning_bg) = alert_colors(AlertKind::Warning);
+        let (_, _, advisory_bg) = alert_colors(AlertKind::Advisory);
+        assert_ne!(warning_bg, advisory_bg);
+    }
+
+    #[test]
+    fn the_pulse_starts_and_ends_on_the_background_color_and_peaks_at_the_accent() {
+        let bg = Rgb565::new(16, 0, 0);
+        let accent = Rgb565::RED;
+
+        assert_eq!(pulse_background(bg, accent, 0), bg);
+        assert_eq!(pulse_background(bg, accent, PULSE_DURATION_MS / 2), accent);
+        assert_eq!(pulse_background(bg, accent, PULSE_DURATION_MS), bg);
+        assert_eq!(pulse_background(bg, accent, PULSE_DURATION_MS + 500), bg);
+    }
+
+    #[test]
+    fn the_pulse_is_between_background_and_accent_partway_through() {
+        let bg = Rgb565::new(16, 0, 0);
+        let accent = Rgb565::new(31, 63, 31);
+
+        let partway = pulse_background(bg, accent, PULSE_DURATION_MS / 4);
+        assert!(partway.r() > bg.r() && partway.r() < accent.r());
+        assert!(partway.g() > bg.g() && partway.g() < accent.g());
+    }
+
+    #[test]
+    fn an_active_alert_registers_a_hit_testable_silence_button() {
+        let screen = Size::new(320, 170);
+        let mut fb = MockFramebuffer::new(screen.width, screen.height);
+        let mut state = AppState::new(AppConfig::default());
+        state
+            .active_alerts
+            .push(crate::alerts::build_synthetic(AlertKind::Warning, "Tornado Warning", 0));
+
+        draw(&mut fb, &mut state, screen).unwrap();
+
+        let button_center = silence_button_rect(screen).center();
+        assert_eq!(state.warnings_buttons.hit_test(button_center), Some(SILENCE_BUTTON_ID));
+    }
+
+    #[test]
+    fn the_no_alerts_screen_registers_no_buttons() {
+        let screen = Size::new(320, 170);
+        let mut fb = MockFramebuffer::new(screen.width, screen.height);
+        let mut state = AppState::new(AppConfig::default());
+
+        draw(&mut fb, &mut state, screen).unwrap();
+
+        assert_eq!(state.warnings_buttons.hit_test(silence_button_rect(screen).center()), None);
+    }
+
+    #[test]
+    fn tapping_the_silence_button_mutes_the_alert() {
+        let screen = Size::new(320, 170);
+        let mut fb = MockFramebuffer::new(screen.width, screen.height);
+        let mut state = AppState::new(AppConfig::default());
+        state.now_ms = 5_000;
+        state
+            .active_alerts
+            .push(crate::alerts::build_synthetic(AlertKind::Warning, "Tornado Warning", 0));
+        draw(&mut fb, &mut state, screen).unwrap();
+
+        let handled = handle_tap(silence_button_rect(screen).center(), &mut state);
+
+        assert!(handled);
+        assert!(state.alert_silence.is_muted());
+    }
+
+    #[test]
+    fn tapping_outside_any_button_is_not_handled() {
+        let mut state = AppState::new(AppConfig::default());
+
+        let handled = handle_tap(Point::new(-100, -100), &mut state);
+
+        assert!(!handled);
+        assert!(!state.alert_silence.is_muted());
+    }
+
+    #[test]
+    fn tokenize_line_marks_only_exact_keyword_matches() {
+        let keywords = vec!["TORNADO".to_string(), "EVACUATE".to_string()];
+        let spans = tokenize_line("TORNADO warning issued, EVACUATE the area now", &keywords);
+
+        assert_eq!(
+            spans,
+            vec![
+                Span { text: "TORNADO", highlighted: true },
+                Span { text: "warning", highlighted: false },
+                Span { text: "issued,", highlighted: false },
+                Span { text: "EVACUATE", highlighted: true },
+                Span { text: "the", highlighted: false },
+                Span { text: "area", highlighted: false },
+                Span { text: "now", highlighted: false },
+            ]
+        );
+    }
+}
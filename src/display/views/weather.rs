@@ -0,0 +1,73 @@
+//! Current-conditions page: the wind compass, the local dew point, and a
+//! stale-data marker. Temperature and humidity are not drawn as text here.
+
+use embedded_graphics::mono_font::ascii::FONT_7X13;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::text::Text;
+
+use crate::display::{status_bar, Framebuffer};
+use crate::state::AppState;
+use crate::weather::comfort::dew_point_c;
+use crate::weather::{arrow_endpoint, is_stale, Point2, STALE_AFTER_MS};
+
+const COMPASS_CENTER: Point2 = Point2 { x: 220.0, y: 80.0 };
+const COMPASS_RADIUS: f32 = 28.0;
+
+/// Draws the compass rose: an arrow along the wind bearing. Wind speed and
+/// gust are not labeled.
+fn draw_compass<D: Framebuffer>(fb: &mut D, bearing_deg: f32) -> Result<(), D::Error> {
+    let tip = arrow_endpoint(COMPASS_CENTER, COMPASS_RADIUS, bearing_deg);
+    let tail = arrow_endpoint(COMPASS_CENTER, COMPASS_RADIUS * 0.4, bearing_deg + 180.0);
+
+    let style = PrimitiveStyle::with_stroke(Rgb565::WHITE, 2);
+    Line::new(
+        Point::new(tail.x as i32, tail.y as i32),
+        Point::new(tip.x as i32, tip.y as i32),
+    )
+    .into_styled(style)
+    .draw(fb)?;
+
+    // Arrowhead: two short barbs back from the tip at +/-25 degrees.
+    for barb_offset in [25.0, -25.0] {
+        let barb = arrow_endpoint(
+            Point2 { x: tip.x, y: tip.y },
+            COMPASS_RADIUS * 0.3,
+            bearing_deg + 180.0 + barb_offset,
+        );
+        Line::new(
+            Point::new(tip.x as i32, tip.y as i32),
+            Point::new(barb.x as i32, barb.y as i32),
+        )
+        .into_styled(style)
+        .draw(fb)?;
+    }
+    Ok(())
+}
+
+/// Draws the local dew point, computed from the BME280 reading rather than
+/// OWM's `feels_like`, which is based on conditions at the weather station.
+fn draw_dew_point<D: Framebuffer>(fb: &mut D, bme: &crate::sensors::BmeReading) -> Result<(), D::Error> {
+    let dp = dew_point_c(bme.temp_c, bme.humidity_pct);
+    let text = format!("Dew point: {dp:.1}C");
+    let style = MonoTextStyle::new(&FONT_7X13, Rgb565::WHITE);
+    Text::new(&text, Point::new(10, 110), style).draw(fb)?;
+    Ok(())
+}
+
+pub fn draw<D: Framebuffer>(fb: &mut D, state: &AppState) -> Result<(), D::Error> {
+    status_bar::draw(fb, state)?;
+    if let Some(wind_deg) = state.weather.as_ref().and_then(|w| w.wind_deg) {
+        draw_compass(fb, wind_deg)?;
+    }
+    if let Some(bme) = &state.bme {
+        draw_dew_point(fb, bme)?;
+    }
+    if is_stale(state.now_ms, state.last_weather_fetch_ms, STALE_AFTER_MS) {
+        let style = MonoTextStyle::new(&FONT_7X13, Rgb565::YELLOW);
+        Text::new("stale", Point::new(10, 130), style).draw(fb)?;
+    }
+    Ok(())
+}
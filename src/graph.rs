@@ -0,0 +1,505 @@
+//! A small reusable line-graph widget, used by the pressure view (and
+//! anything else that wants to plot a rolling series).
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line, PrimitiveStyle, Rectangle};
+
+/// Visual style for a graph; configurable (via `AppConfig`, not part of the
+/// NVS-persisted settings) so users can pick colors/line weight that suit
+/// their screen and lighting.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphStyle {
+    pub line_color: Rgb565,
+    pub stroke_width: u32,
+    pub background: Rgb565,
+    /// Number of horizontal gridlines, evenly spaced across the graph
+    /// height. 0 disables them.
+    pub grid_lines: u32,
+    pub grid_color: Rgb565,
+    /// Whether to also draw vertical gridlines at `vertical_gridline_interval_secs`
+    /// marks, using the same X scaling as the plotted data.
+    pub vertical_gridlines_enabled: bool,
+    pub vertical_gridline_interval_secs: u64,
+}
+
+impl Default for GraphStyle {
+    fn default() -> Self {
+        Self {
+            line_color: Rgb565::CYAN,
+            stroke_width: 1,
+            background: Rgb565::BLACK,
+            grid_lines: 3,
+            grid_color: Rgb565::new(8, 16, 8),
+            vertical_gridlines_enabled: false,
+            vertical_gridline_interval_secs: 6 * 3_600,
+        }
+    }
+}
+
+/// Y pixel offsets (from the top of a `height`-px-tall area) of `count`
+/// evenly spaced horizontal gridlines, excluding the top/bottom edges.
+/// E.g. 3 lines in a 100px area land at 25/50/75.
+pub fn gridline_y_positions(height: u32, count: u32) -> Vec<i32> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let height = height as f32;
+    let step = height / (count + 1) as f32;
+    (1..=count).map(|i| (step * i as f32) as i32).collect()
+}
+
+/// X pixel offsets of vertical gridlines spaced `interval_secs` apart
+/// across a series of `sample_count` points taken `sample_period_secs`
+/// apart, scaled the same way [`draw_line_graph`] scales the X axis (evenly
+/// across `width` by sample index, not by absolute time).
+pub fn vertical_gridline_x_positions(
+    width: u32,
+    sample_count: usize,
+    sample_period_secs: u64,
+    interval_secs: u64,
+) -> Vec<i32> {
+    if sample_count < 2 || sample_period_secs == 0 || interval_secs == 0 {
+        return Vec::new();
+    }
+    let samples_per_interval = (interval_secs / sample_period_secs).max(1) as usize;
+    let width = width as f32;
+    let last_index = sample_count - 1;
+    (samples_per_interval..sample_count)
+        .step_by(samples_per_interval)
+        .map(|i| (i as f32 / last_index as f32 * width) as i32)
+        .collect()
+}
+
+/// Pixel X offsets for `timestamps`, placing each point proportionally to
+/// its actual elapsed time since the first sample rather than its index —
+/// so a delayed or missed sample (Wi-Fi stall, deep sleep) renders as a
+/// wider gap instead of silently compressing into its neighbors.
+/// `timestamps` is assumed non-decreasing. A single sample, or a series
+/// spanning zero time, places every point at the left edge.
+pub fn time_scaled_x_positions(timestamps: &[u64], width: u32) -> Vec<i32> {
+    let (Some(&first), Some(&last)) = (timestamps.first(), timestamps.last()) else {
+        return Vec::new();
+    };
+    let span = last.saturating_sub(first);
+    if span == 0 {
+        return vec![0; timestamps.len()];
+    }
+    let w = width as f32;
+    timestamps
+        .iter()
+        .map(|&t| (t.saturating_sub(first) as f32 / span as f32 * w) as i32)
+        .collect()
+}
+
+/// Picks at most `target_width` indices into `values` (in display order),
+/// one per output pixel column, so a long history doesn't draw more line
+/// segments than the screen has columns for. Each column alternates
+/// picking its bucket's min or max index (classic waveform decimation) so
+/// spikes survive being zoomed out instead of averaging away; the series'
+/// true global min and global max indices are then forced in, in case
+/// neither landed on a column that happened to ask for that extreme.
+/// Returns every index unchanged if `values` already fits within
+/// `target_width`.
+pub fn downsample_indices(values: &[f32], target_width: usize) -> Vec<usize> {
+    if target_width == 0 || values.len() <= target_width {
+        return (0..values.len()).collect();
+    }
+
+    let bucket_size = values.len() as f32 / target_width as f32;
+    let bucket_range = |col: usize| -> std::ops::Range<usize> {
+        let start = (col as f32 * bucket_size) as usize;
+        let end = (((col + 1) as f32 * bucket_size) as usize)
+            .max(start + 1)
+            .min(values.len());
+        start..end
+    };
+    let cmp = |&a: &usize, &b: &usize| values[a].partial_cmp(&values[b]).unwrap();
+
+    let mut indices: Vec<usize> = (0..target_width)
+        .map(|col| {
+            let bucket = bucket_range(col);
+            if col % 2 == 0 {
+                bucket.min_by(cmp).unwrap()
+            } else {
+                bucket.max_by(cmp).unwrap()
+            }
+        })
+        .collect();
+
+    let index_cmp = |&a: &usize, &b: &usize| values[a].partial_cmp(&values[b]).unwrap();
+    if let Some(global_min_i) = (0..values.len()).min_by(index_cmp) {
+        let col = ((global_min_i as f32 / bucket_size) as usize).min(target_width - 1);
+        indices[col] = global_min_i;
+    }
+    if let Some(global_max_i) = (0..values.len()).max_by(index_cmp) {
+        let col = ((global_max_i as f32 / bucket_size) as usize).min(target_width - 1);
+        indices[col] = global_max_i;
+    }
+    indices
+}
+
+/// Inverse of the index-based X scaling [`draw_line_graph`] uses when no
+/// `timestamps` are given (`x = i / (point_count - 1) * width`): maps a
+/// touched pixel X back to the nearest plotted index, so a touch can be
+/// turned into "which sample was tapped". Returns `None` for a touch
+/// outside the plotted area (`x < 0` or `x > width`), or for fewer than
+/// two points (nothing to index into).
+pub fn index_from_x(x: i32, width: u32, point_count: usize) -> Option<usize> {
+    if point_count < 2 || width == 0 || x < 0 || x > width as i32 {
+        return None;
+    }
+    let last_index = (point_count - 1) as f32;
+    let i = (x as f32 / width as f32 * last_index).round() as usize;
+    Some(i.min(point_count - 1))
+}
+
+/// Centered simple moving average over `values`, one output per input
+/// point (the window shrinks near the edges rather than leaving them
+/// unsmoothed or dropping them). `window <= 1` returns `values` unchanged.
+pub fn moving_average(values: &[f32], window: usize) -> Vec<f32> {
+    if window <= 1 || values.is_empty() {
+        return values.to_vec();
+    }
+    let half = window / 2;
+    (0..values.len())
+        .map(|i| {
+            let start = i.saturating_sub(half);
+            let end = (i + half + 1).min(values.len());
+            let slice = &values[start..end];
+            slice.iter().sum::<f32>() / slice.len() as f32
+        })
+        .collect()
+}
+
+/// Index of the minimum and maximum values in a non-empty slice, first
+/// occurrence wins on ties.
+pub fn min_max_indices(values: &[f32]) -> Option<(usize, usize)> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut min_i = 0;
+    let mut max_i = 0;
+    for (i, &v) in values.iter().enumerate() {
+        if v < values[min_i] {
+            min_i = i;
+        }
+        if v > values[max_i] {
+            max_i = i;
+        }
+    }
+    Some((min_i, max_i))
+}
+
+/// Draws `values` as a polyline filling `area`, scaled so the min/max of
+/// `values` span the full height, with small markers at the min and max
+/// points. With fewer than two `values` only the background and
+/// horizontal gridlines are drawn (nothing to connect).
+/// `sample_period_secs` is only used to scale vertical gridlines (ignored
+/// if `style.vertical_gridlines_enabled` is false).
+/// `timestamps`, if given, places points by actual elapsed time (see
+/// [`time_scaled_x_positions`]) rather than assuming an even cadence; it
+/// must be the same length as `values`.
+pub fn draw_line_graph<D>(
+    fb: &mut D,
+    area: Rectangle,
+    values: &[f32],
+    style: GraphStyle,
+    sample_period_secs: u64,
+    timestamps: Option<&[u64]>,
+) -> Result<(), D::Error>
+where
+    D: embedded_graphics::draw_target::DrawTarget<Color = Rgb565>,
+{
+    Rectangle::new(area.top_left, area.size)
+        .into_styled(PrimitiveStyle::with_fill(style.background))
+        .draw(fb)?;
+
+    let grid_style = PrimitiveStyle::with_stroke(style.grid_color, 1);
+    for y in gridline_y_positions(area.size.height, style.grid_lines) {
+        let y = area.top_left.y + y;
+        Line::new(Point::new(area.top_left.x, y), Point::new(area.top_left.x + area.size.width as i32, y))
+            .into_styled(grid_style)
+            .draw(fb)?;
+    }
+
+    if values.len() < 2 {
+        return Ok(());
+    }
+
+    if style.vertical_gridlines_enabled {
+        for x in vertical_gridline_x_positions(
+            area.size.width,
+            values.len(),
+            sample_period_secs,
+            style.vertical_gridline_interval_secs,
+        ) {
+            let x = area.top_left.x + x;
+            Line::new(Point::new(x, area.top_left.y), Point::new(x, area.top_left.y + area.size.height as i32))
+                .into_styled(grid_style)
+                .draw(fb)?;
+        }
+    }
+
+    let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+
+    let w = area.size.width as f32;
+    let h = area.size.height as f32;
+    let x0 = area.top_left.x;
+    let y0 = area.top_left.y;
+
+    let x_positions: Option<Vec<i32>> = timestamps.map(|ts| time_scaled_x_positions(ts, area.size.width));
+
+    let to_point = |i: usize, v: f32| -> Point {
+        let x = x0
+            + match &x_positions {
+                Some(positions) => positions[i],
+                None => (i as f32 / (values.len() - 1) as f32 * w) as i32,
+            };
+        let y = y0 + (h - (v - min) / range * h) as i32;
+        Point::new(x, y)
+    };
+
+    let line_style = PrimitiveStyle::with_stroke(style.line_color, style.stroke_width);
+    for window in values.windows(2).enumerate() {
+        let (i, pair) = window;
+        let p0 = to_point(i, pair[0]);
+        let p1 = to_point(i + 1, pair[1]);
+        Line::new(p0, p1).into_styled(line_style).draw(fb)?;
+    }
+
+    if let Some((min_i, max_i)) = min_max_indices(values) {
+        let marker_style = PrimitiveStyle::with_fill(Rgb565::YELLOW);
+        for i in [min_i, max_i] {
+            let p = to_point(i, values[i]);
+            Circle::with_center(p, 5)
+                .into_styled(marker_style)
+                .draw(fb)?;
+        }
+    }
+    Ok(())
+}
+
+/// Maps `value` within `[min, max]` to a pixel Y offset in a `height`-px
+/// area (min at the bottom, max at the top). Used by
+/// [`draw_overlay_line`] to scale a second series independently of
+/// whatever scale the primary series uses.
+pub fn scale_to_y(value: f32, min: f32, max: f32, height: u32) -> i32 {
+    let range = (max - min).max(f32::EPSILON);
+    let h = height as f32;
+    (h - (value - min) / range * h) as i32
+}
+
+/// Draws a second series with its own independent Y scale over an
+/// already-drawn graph (e.g. outdoor temperature alongside pressure), using
+/// the same X scaling as [`draw_line_graph`] so points line up. `values`
+/// must be the same length and cadence as the primary series; a `None`
+/// entry breaks the line across that gap rather than interpolating.
+pub fn draw_overlay_line<D>(
+    fb: &mut D,
+    area: Rectangle,
+    values: &[Option<f32>],
+    color: Rgb565,
+    stroke_width: u32,
+) -> Result<(), D::Error>
+where
+    D: embedded_graphics::draw_target::DrawTarget<Color = Rgb565>,
+{
+    let present: Vec<f32> = values.iter().filter_map(|v| *v).collect();
+    if present.len() < 2 || values.len() < 2 {
+        return Ok(());
+    }
+    let min = present.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = present.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+    let w = area.size.width as f32;
+    let last_index = values.len() - 1;
+    let style = PrimitiveStyle::with_stroke(color, stroke_width);
+    let mut prev: Option<Point> = None;
+    for (i, v) in values.iter().enumerate() {
+        match v {
+            Some(val) => {
+                let x = area.top_left.x + (i as f32 / last_index as f32 * w) as i32;
+                let y = area.top_left.y + scale_to_y(*val, min, max, area.size.height);
+                let p = Point::new(x, y);
+                if let Some(prev_p) = prev {
+                    Line::new(prev_p, p).into_styled(style).draw(fb)?;
+                }
+                prev = Some(p);
+            }
+            None => prev = None,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_min_and_max_indices() {
+        let values = [3.0, 1.0, 4.0, 1.0, 5.0, 0.5];
+        assert_eq!(min_max_indices(&values), Some((5, 4)));
+    }
+
+    #[test]
+    fn empty_slice_has_no_min_max() {
+        assert_eq!(min_max_indices(&[]), None);
+    }
+
+    #[test]
+    fn single_value_is_its_own_min_and_max() {
+        assert_eq!(min_max_indices(&[7.0]), Some((0, 0)));
+    }
+
+    #[test]
+    fn gridline_y_positions_are_evenly_spaced() {
+        assert_eq!(gridline_y_positions(100, 3), vec![25, 50, 75]);
+    }
+
+    #[test]
+    fn zero_gridlines_yields_no_positions() {
+        assert_eq!(gridline_y_positions(100, 0), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn one_gridline_lands_at_the_midpoint() {
+        assert_eq!(gridline_y_positions(200, 1), vec![100]);
+    }
+
+    #[test]
+    fn vertical_gridlines_land_at_interval_boundaries() {
+        // 25 samples (indices 0-24) at 10min each; a 1h interval gridline
+        // lands every 6 samples.
+        let positions = vertical_gridline_x_positions(240, 25, 600, 3_600);
+        assert_eq!(positions, vec![60, 120, 180, 240]);
+    }
+
+    #[test]
+    fn vertical_gridlines_empty_with_too_few_samples() {
+        assert_eq!(vertical_gridline_x_positions(240, 1, 600, 3_600), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn scale_to_y_puts_the_minimum_at_the_bottom() {
+        assert_eq!(scale_to_y(0.0, 0.0, 100.0, 200), 200);
+    }
+
+    #[test]
+    fn scale_to_y_puts_the_maximum_at_the_top() {
+        assert_eq!(scale_to_y(100.0, 0.0, 100.0, 200), 0);
+    }
+
+    #[test]
+    fn scale_to_y_for_a_known_midpoint_temp() {
+        assert_eq!(scale_to_y(50.0, 0.0, 100.0, 200), 100);
+    }
+
+    #[test]
+    fn time_scaled_x_positions_spreads_evenly_spaced_timestamps_like_index_scaling() {
+        let timestamps = [0, 100, 200, 300, 400];
+        assert_eq!(time_scaled_x_positions(&timestamps, 400), vec![0, 100, 200, 300, 400]);
+    }
+
+    #[test]
+    fn time_scaled_x_positions_widens_the_gap_around_a_delayed_sample() {
+        // Evenly spaced except the 3rd sample arrived late (600 instead of
+        // 200) — its gap from the previous point should be visibly wider
+        // than the others, and the following point should be pulled along.
+        let timestamps = [0, 100, 600, 700, 800];
+        let positions = time_scaled_x_positions(&timestamps, 800);
+        assert_eq!(positions, vec![0, 100, 600, 700, 800]);
+        assert!(positions[2] - positions[1] > positions[1] - positions[0]);
+    }
+
+    #[test]
+    fn time_scaled_x_positions_is_empty_for_an_empty_series() {
+        assert_eq!(time_scaled_x_positions(&[], 400), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn time_scaled_x_positions_places_a_zero_span_series_at_the_left_edge() {
+        assert_eq!(time_scaled_x_positions(&[500, 500, 500], 400), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn downsample_indices_is_a_no_op_under_the_target_width() {
+        let values = [1.0, 2.0, 3.0];
+        assert_eq!(downsample_indices(&values, 10), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn downsample_indices_decimates_a_dense_series_to_the_target_width_preserving_min_max() {
+        // 144 samples (a day of 10-minute history) down to a 40px-wide
+        // graph. The true min sits early, the true max sits late, well
+        // apart so they land in different buckets.
+        let values: Vec<f32> = (0..144)
+            .map(|i| 1000.0 + (i as f32 * 0.3).sin() * 5.0 + i as f32 * 0.05)
+            .collect();
+        let target_width = 40;
+
+        let indices = downsample_indices(&values, target_width);
+        assert!(indices.len() <= target_width);
+
+        let decimated: Vec<f32> = indices.iter().map(|&i| values[i]).collect();
+        let global_min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+        let global_max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let decimated_min = decimated.iter().cloned().fold(f32::INFINITY, f32::min);
+        let decimated_max = decimated.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+        assert_eq!(decimated_min, global_min);
+        assert_eq!(decimated_max, global_max);
+    }
+
+    #[test]
+    fn downsample_indices_are_non_decreasing_display_order() {
+        let values: Vec<f32> = (0..50).map(|i| i as f32).collect();
+        let indices = downsample_indices(&values, 10);
+        assert!(indices.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn moving_average_of_a_constant_series_is_unchanged() {
+        let values = [5.0; 10];
+        assert_eq!(moving_average(&values, 3), vec![5.0; 10]);
+    }
+
+    #[test]
+    fn moving_average_smooths_a_single_spike() {
+        let values = [0.0, 0.0, 10.0, 0.0, 0.0];
+        let smoothed = moving_average(&values, 3);
+        assert!(smoothed[2] < 10.0);
+        assert!(smoothed[2] > smoothed[0]);
+    }
+
+    #[test]
+    fn index_from_x_round_trips_with_the_forward_index_scaling() {
+        let width = 400;
+        let point_count = 9; // indices 0..=8
+        for i in 0..point_count {
+            let x = (i as f32 / (point_count - 1) as f32 * width as f32) as i32;
+            assert_eq!(index_from_x(x, width, point_count), Some(i));
+        }
+    }
+
+    #[test]
+    fn index_from_x_is_none_outside_the_plotted_range() {
+        assert_eq!(index_from_x(-1, 400, 9), None);
+        assert_eq!(index_from_x(401, 400, 9), None);
+    }
+
+    #[test]
+    fn index_from_x_is_none_with_fewer_than_two_points() {
+        assert_eq!(index_from_x(10, 400, 1), None);
+    }
+
+    #[test]
+    fn a_window_of_one_or_less_is_a_no_op() {
+        let values = [1.0, 2.0, 3.0];
+        assert_eq!(moving_average(&values, 1), values.to_vec());
+        assert_eq!(moving_average(&values, 0), values.to_vec());
+    }
+}
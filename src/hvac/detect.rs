@@ -0,0 +1,346 @@
+//! Derives HVAC run-state (heating/cooling/idle) from the local BME280
+//! temperature trend, gating out readings that are obviously out of range
+//! (see [`PLAUSIBLE_TEMP_C`]) so a bad I2C read can't report a false
+//! heating/cooling cycle.
+
+use super::{HvacMode, HvacSample};
+use crate::ring_buffer::RingBuffer;
+use crate::sensors::BmeReading;
+use serde::Serialize;
+
+/// Plausible indoor temperature range; readings outside this are treated
+/// as sensor glitches rather than real HVAC activity.
+const PLAUSIBLE_TEMP_C: std::ops::RangeInclusive<f32> = -20.0..=60.0;
+
+/// How many recent mode transitions the `hvactrans` console command can
+/// look back at.
+const TRANSITION_LOG_CAPACITY: usize = 20;
+
+/// A single committed heating/idle/cooling change, recorded for the
+/// `hvactrans` console command so a hunting or flapping system can be
+/// diagnosed after the fact rather than only live via `hvac debug`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Transition {
+    pub from: HvacMode,
+    pub to: HvacMode,
+    pub at_ms: u64,
+    pub slope_c_per_min: f32,
+    /// How long the detector sat in `from` before flipping to `to`.
+    pub prior_duration_ms: u64,
+}
+
+/// Default temperature slope (°C/minute) above which we call it heating,
+/// below which we call it cooling, used until overridden by
+/// [`crate::settings::Thresholds::hvac_slope_threshold_c_per_min`].
+const DEFAULT_SLOPE_THRESHOLD_C_PER_MIN: f32 = 0.05;
+
+/// How many recent valid samples the slope is fit over. Smooths out
+/// single-reading noise compared to a plain two-point difference.
+const FAST_WINDOW: usize = 5;
+
+/// Ordinary least-squares slope (y per unit x) over a set of (x, y)
+/// points. Returns 0.0 for fewer than two points or zero x-variance.
+fn least_squares_slope(points: &[(f32, f32)]) -> f32 {
+    let n = points.len() as f32;
+    if points.len() < 2 {
+        return 0.0;
+    }
+    let sum_x: f32 = points.iter().map(|(x, _)| x).sum();
+    let sum_y: f32 = points.iter().map(|(_, y)| y).sum();
+    let sum_xx: f32 = points.iter().map(|(x, _)| x * x).sum();
+    let sum_xy: f32 = points.iter().map(|(x, y)| x * y).sum();
+
+    let denom = n * sum_xx - sum_x * sum_x;
+    if denom.abs() < f32::EPSILON {
+        return 0.0;
+    }
+    (n * sum_xy - sum_x * sum_y) / denom
+}
+
+pub struct HvacDetector {
+    last_valid: Option<(u64, f32)>,
+    fast_window: RingBuffer<(u64, f32), FAST_WINDOW>,
+    slope_threshold_c_per_min: f32,
+    /// The mode currently committed to, and when it started, used to
+    /// detect transitions and time their prior duration. `None` until the
+    /// first sample is pushed.
+    current_state: Option<(HvacMode, u64)>,
+    transitions: RingBuffer<Transition, TRANSITION_LOG_CAPACITY>,
+    /// Gates `log::debug!` of each transition; off by default since a
+    /// hunting system can flip several times a minute.
+    debug_logging: bool,
+}
+
+impl HvacDetector {
+    pub fn new() -> Self {
+        Self {
+            last_valid: None,
+            fast_window: RingBuffer::new(),
+            slope_threshold_c_per_min: DEFAULT_SLOPE_THRESHOLD_C_PER_MIN,
+            current_state: None,
+            transitions: RingBuffer::new(),
+            debug_logging: false,
+        }
+    }
+
+    /// Overrides the heating/cooling slope threshold (always in °C/min;
+    /// convert with [`crate::settings::Units::slope_to_c_per_min`] first
+    /// if the value came from a user entry in °F/min).
+    pub fn set_slope_threshold_c_per_min(&mut self, threshold: f32) {
+        self.slope_threshold_c_per_min = threshold;
+    }
+
+    /// Enables or disables `log::debug!` logging of each committed mode
+    /// transition.
+    pub fn set_debug_logging(&mut self, enabled: bool) {
+        self.debug_logging = enabled;
+    }
+
+    /// Recent mode transitions, oldest first, for the `hvactrans` console
+    /// command.
+    pub fn recent_transitions(&self) -> impl Iterator<Item = &Transition> {
+        self.transitions.iter()
+    }
+
+    /// How long the detector has been in its currently committed mode, as
+    /// of `now_ms`. `None` before the first sample has been pushed.
+    pub fn state_duration_secs(&self, now_ms: u64) -> Option<u64> {
+        self.current_state
+            .map(|(_, started_ms)| now_ms.saturating_sub(started_ms) / 1_000)
+    }
+
+    /// Records a transition if `mode` differs from the currently committed
+    /// state, and always updates `current_state` to match.
+    fn record_transition(&mut self, mode: HvacMode, now_ms: u64, slope_c_per_min: f32) {
+        match self.current_state {
+            Some((from, started_ms)) if from != mode => {
+                let transition = Transition {
+                    from,
+                    to: mode,
+                    at_ms: now_ms,
+                    slope_c_per_min,
+                    prior_duration_ms: now_ms.saturating_sub(started_ms),
+                };
+                if self.debug_logging {
+                    log::debug!(
+                        "hvac transition: {:?} -> {:?} after {}ms (slope {:.4} C/min)",
+                        transition.from,
+                        transition.to,
+                        transition.prior_duration_ms,
+                        transition.slope_c_per_min
+                    );
+                }
+                self.transitions.push(transition);
+                self.current_state = Some((mode, now_ms));
+            }
+            Some(_) => {}
+            None => self.current_state = Some((mode, now_ms)),
+        }
+    }
+
+    /// Returns `true` if the reading is plausible enough to feed into the
+    /// detector (as opposed to a failed/garbage I2C transaction).
+    fn is_valid(reading: &BmeReading) -> bool {
+        PLAUSIBLE_TEMP_C.contains(&reading.temp_c) && (0.0..=100.0).contains(&reading.humidity_pct)
+    }
+
+    /// Feeds one BME280 reading at time `now_ms`. Returns the detected
+    /// mode for this tick, or `None` if the reading was gated out (not
+    /// enough valid history yet, or this reading itself looked bad).
+    pub fn push(&mut self, now_ms: u64, reading: &BmeReading) -> Option<HvacSample> {
+        if !Self::is_valid(reading) {
+            log::warn!("BME reading out of range, skipping HVAC detection: {reading:?}");
+            return None;
+        }
+
+        self.fast_window.push((now_ms, reading.temp_c));
+        let points: heapless::Vec<(f32, f32), FAST_WINDOW> = self
+            .fast_window
+            .iter()
+            .map(|&(t, temp)| (t as f32 / 60_000.0, temp))
+            .collect();
+        let slope = least_squares_slope(&points);
+
+        let mode = if self.fast_window.len() < 2 {
+            HvacMode::Idle
+        } else if slope > self.slope_threshold_c_per_min {
+            HvacMode::Heating
+        } else if slope < -self.slope_threshold_c_per_min {
+            HvacMode::Cooling
+        } else {
+            HvacMode::Idle
+        };
+
+        self.record_transition(mode, now_ms, slope);
+        self.last_valid = Some((now_ms, reading.temp_c));
+        Some(HvacSample {
+            mode,
+            timestamp_ms: now_ms,
+        })
+    }
+}
+
+impl Default for HvacDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A snapshot of the detector's internal state, for a live debug readout
+/// (the numbers that actually drove the last mode decision, not just the
+/// decision itself).
+#[derive(Debug, Clone, Copy)]
+pub struct DetectorSnapshot {
+    pub fast_window_len: usize,
+    pub current_slope_c_per_min: f32,
+    pub last_temp_c: Option<f32>,
+}
+
+impl HvacDetector {
+    pub fn snapshot(&self) -> DetectorSnapshot {
+        let points: heapless::Vec<(f32, f32), FAST_WINDOW> = self
+            .fast_window
+            .iter()
+            .map(|&(t, temp)| (t as f32 / 60_000.0, temp))
+            .collect();
+        DetectorSnapshot {
+            fast_window_len: self.fast_window.len(),
+            current_slope_c_per_min: least_squares_slope(&points),
+            last_temp_c: self.last_valid.map(|(_, temp)| temp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+
+    fn reading(temp_c: f32) -> BmeReading {
+        BmeReading {
+            temp_c,
+            humidity_pct: 40.0,
+            pressure_hpa: 1013.0,
+        }
+    }
+
+    #[test]
+    fn least_squares_matches_exact_linear_fit() {
+        let points = [(0.0, 20.0), (1.0, 21.0), (2.0, 22.0), (3.0, 23.0)];
+        assert!((least_squares_slope(&points) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn least_squares_smooths_noisy_points() {
+        // Trend is +1/x with a little noise; slope should still be close
+        // to 1.0 rather than whatever the noisiest two-point diff gives.
+        let points = [(0.0, 20.0), (1.0, 20.8), (2.0, 22.3), (3.0, 22.9)];
+        let slope = least_squares_slope(&points);
+        assert!((slope - 1.0).abs() < 0.3, "got {slope}");
+    }
+
+    #[test]
+    fn invalid_reading_is_gated_out() {
+        let mut d = HvacDetector::new();
+        let bad = reading(999.0);
+        assert!(d.push(0, &bad).is_none());
+    }
+
+    #[test]
+    fn first_valid_reading_has_no_trend_yet() {
+        let mut d = HvacDetector::new();
+        let sample = d.push(0, &reading(21.0)).unwrap();
+        assert_eq!(sample.mode, HvacMode::Idle);
+    }
+
+    #[test]
+    fn rising_temperature_reads_as_heating() {
+        let mut d = HvacDetector::new();
+        d.push(0, &reading(20.0));
+        let sample = d.push(60_000, &reading(20.5)).unwrap();
+        assert_eq!(sample.mode, HvacMode::Heating);
+    }
+
+    #[test]
+    fn falling_temperature_reads_as_cooling() {
+        let mut d = HvacDetector::new();
+        d.push(0, &reading(24.0));
+        let sample = d.push(60_000, &reading(23.5)).unwrap();
+        assert_eq!(sample.mode, HvacMode::Cooling);
+    }
+
+    #[test]
+    fn bad_reading_does_not_corrupt_the_trend_baseline() {
+        let mut d = HvacDetector::new();
+        d.push(0, &reading(20.0));
+        d.push(30_000, &reading(500.0)); // gated out
+        let sample = d.push(60_000, &reading(20.5)).unwrap();
+        assert_eq!(sample.mode, HvacMode::Heating);
+    }
+
+    #[test]
+    fn a_mode_change_records_the_prior_states_duration() {
+        let mut d = HvacDetector::new();
+        d.push(0, &reading(20.0)); // Idle (no trend yet)
+        d.push(60_000, &reading(20.0)); // still Idle, flat trend
+        let sample = d.push(300_000, &reading(21.0)).unwrap(); // Heating
+        assert_eq!(sample.mode, HvacMode::Heating);
+
+        let transitions: Vec<_> = d.recent_transitions().collect();
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].from, HvacMode::Idle);
+        assert_eq!(transitions[0].to, HvacMode::Heating);
+        assert_eq!(transitions[0].at_ms, 300_000);
+        assert_eq!(transitions[0].prior_duration_ms, 300_000);
+    }
+
+    #[test]
+    fn a_stable_mode_records_no_transitions() {
+        let mut d = HvacDetector::new();
+        d.push(0, &reading(20.0));
+        d.push(60_000, &reading(20.0));
+        d.push(120_000, &reading(20.0));
+        assert_eq!(d.recent_transitions().count(), 0);
+    }
+
+    #[test]
+    fn state_duration_secs_is_none_before_the_first_sample() {
+        let d = HvacDetector::new();
+        assert_eq!(d.state_duration_secs(0), None);
+    }
+
+    #[test]
+    fn state_duration_secs_tracks_an_advancing_clock() {
+        let mut clock = MockClock::new();
+        let mut d = HvacDetector::new();
+
+        d.push(clock.now_ms(), &reading(20.0)); // Idle
+        assert_eq!(d.state_duration_secs(clock.now_ms()), Some(0));
+
+        clock.advance_ms(30_000);
+        assert_eq!(d.state_duration_secs(clock.now_ms()), Some(30));
+
+        clock.advance_ms(90_000);
+        let sample = d.push(clock.now_ms(), &reading(21.0)).unwrap(); // Heating
+        assert_eq!(sample.mode, HvacMode::Heating);
+        assert_eq!(d.state_duration_secs(clock.now_ms()), Some(0));
+
+        clock.advance_ms(15_000);
+        assert_eq!(d.state_duration_secs(clock.now_ms()), Some(15));
+    }
+
+    /// Every timestamp here is already `u64` milliseconds (never derived
+    /// from a `u32` cast of a microsecond counter), so a duration spanning
+    /// where a `u32` millisecond count would have wrapped (~49.7 days, at
+    /// `u32::MAX` ms) must still compute correctly rather than going
+    /// negative/garbage.
+    #[test]
+    fn duration_spanning_the_old_u32_ms_boundary_is_still_correct() {
+        let near_u32_boundary = u32::MAX as u64 - 30_000;
+        let mut d = HvacDetector::new();
+        d.push(near_u32_boundary, &reading(20.0));
+
+        let past_boundary = near_u32_boundary + 60_000;
+        assert_eq!(d.state_duration_secs(past_boundary), Some(60));
+    }
+}
@@ -0,0 +1,255 @@
+//! HVAC run-state detection and history, derived from the BME280
+//! temperature slope (see [`crate::hvac::detect`]).
+
+pub mod detect;
+
+use crate::ring_buffer::RingBuffer;
+use serde::Serialize;
+
+pub use detect::HvacDetector;
+
+/// 24h of history at a 5-minute sample interval.
+pub const TIMELINE_CAPACITY: usize = 288;
+
+/// How often a mode sample lands in the timeline, used together with
+/// [`crate::thresholds::HVAC_MIN_HISTORY_MINUTES`] to gate the "collecting
+/// data" state on elapsed time rather than a raw sample count.
+pub const SAMPLE_PERIOD_SECS: u64 = 5 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum HvacMode {
+    Idle,
+    Heating,
+    Cooling,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HvacSample {
+    pub mode: HvacMode,
+    pub timestamp_ms: u64,
+}
+
+/// Rolling history of HVAC mode samples, used by the timeline strip. The
+/// buffer itself stays a fixed-size, `Copy` `RingBuffer` (it's placed in
+/// RTC slow memory across deep sleep, see [`crate::power::rtc_memory`]),
+/// but the cadence it's sampled at is a runtime field rather than the
+/// compile-time [`SAMPLE_PERIOD_SECS`] default, so the ~24h window it
+/// represents can be widened or narrowed without a rebuild.
+#[derive(Clone, Copy)]
+pub struct HvacTimeline {
+    samples: RingBuffer<HvacSample, TIMELINE_CAPACITY>,
+    sample_period_secs: u64,
+}
+
+impl HvacTimeline {
+    pub const fn new() -> Self {
+        Self {
+            samples: RingBuffer::new(),
+            sample_period_secs: SAMPLE_PERIOD_SECS,
+        }
+    }
+
+    pub fn push(&mut self, sample: HvacSample) {
+        self.samples.push(sample);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HvacSample> {
+        self.samples.iter()
+    }
+
+    /// How often, in seconds, a sample is expected to land in this
+    /// timeline. Defaults to [`SAMPLE_PERIOD_SECS`].
+    pub fn sample_period_secs(&self) -> u64 {
+        self.sample_period_secs
+    }
+
+    /// Overrides the sampling cadence this timeline represents. The
+    /// buffer's capacity (and therefore the total history span,
+    /// `capacity * sample_period_secs`) stays fixed; only the cadence
+    /// changes.
+    pub fn set_sample_period_secs(&mut self, secs: u64) {
+        self.sample_period_secs = secs;
+    }
+}
+
+impl Default for HvacTimeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detects "hunting": the HVAC system rapidly flipping between heating and
+/// cooling (or on/off) rather than settling, usually a sign of a setpoint
+/// too close to ambient or a miscalibrated sensor. `samples` must be in
+/// chronological order.
+pub fn is_hunting(samples: &[HvacSample], window_ms: u64, max_transitions: usize) -> bool {
+    let Some(latest) = samples.last() else {
+        return false;
+    };
+    let cutoff = latest.timestamp_ms.saturating_sub(window_ms);
+    let in_window: Vec<&HvacSample> = samples
+        .iter()
+        .rev()
+        .take_while(|s| s.timestamp_ms >= cutoff)
+        .collect();
+
+    let transitions = in_window
+        .windows(2)
+        .filter(|pair| pair[0].mode != pair[1].mode)
+        .count();
+    transitions > max_transitions
+}
+
+/// Summary counts for the "hvac stats" console command.
+#[derive(Debug, Serialize)]
+pub struct HvacStats {
+    pub total_samples: usize,
+    pub heating_samples: usize,
+    pub cooling_samples: usize,
+    pub idle_samples: usize,
+    pub hunting: bool,
+}
+
+impl HvacStats {
+    /// Pixel widths of the heating/cooling/idle segments of a stacked
+    /// runtime-proportion bar `total_width` px wide, in that order. With no
+    /// samples at all, the bar is shown all-idle rather than empty.
+    pub fn segment_widths(&self, total_width: u32) -> (u32, u32, u32) {
+        if self.total_samples == 0 {
+            return (0, 0, total_width);
+        }
+        let heating_width =
+            (total_width as u64 * self.heating_samples as u64 / self.total_samples as u64) as u32;
+        let cooling_width =
+            (total_width as u64 * self.cooling_samples as u64 / self.total_samples as u64) as u32;
+        // Idle takes the remainder so the three segments always sum to
+        // `total_width` exactly, regardless of integer-division rounding.
+        let idle_width = total_width - heating_width - cooling_width;
+        (heating_width, cooling_width, idle_width)
+    }
+}
+
+pub fn compute_stats(samples: &[HvacSample]) -> HvacStats {
+    let heating_samples = samples.iter().filter(|s| s.mode == HvacMode::Heating).count();
+    let cooling_samples = samples.iter().filter(|s| s.mode == HvacMode::Cooling).count();
+    let idle_samples = samples.iter().filter(|s| s.mode == HvacMode::Idle).count();
+    HvacStats {
+        total_samples: samples.len(),
+        heating_samples,
+        cooling_samples,
+        idle_samples,
+        hunting: is_hunting(samples, 30 * 60 * 1_000, 4),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(mode: HvacMode, t: u64) -> HvacSample {
+        HvacSample {
+            mode,
+            timestamp_ms: t,
+        }
+    }
+
+    #[test]
+    fn stable_mode_is_not_hunting() {
+        let samples = vec![
+            sample(HvacMode::Heating, 0),
+            sample(HvacMode::Heating, 1000),
+            sample(HvacMode::Heating, 2000),
+        ];
+        assert!(!is_hunting(&samples, 10_000, 2));
+    }
+
+    #[test]
+    fn rapid_flipping_within_window_is_hunting() {
+        let samples = vec![
+            sample(HvacMode::Idle, 0),
+            sample(HvacMode::Heating, 500),
+            sample(HvacMode::Idle, 1000),
+            sample(HvacMode::Heating, 1500),
+            sample(HvacMode::Idle, 2000),
+        ];
+        assert!(is_hunting(&samples, 10_000, 2));
+    }
+
+    #[test]
+    fn flipping_outside_window_is_ignored() {
+        let samples = vec![
+            sample(HvacMode::Idle, 0),
+            sample(HvacMode::Heating, 1_000),
+            sample(HvacMode::Idle, 2_000),
+            sample(HvacMode::Heating, 3_000),
+            // Long quiet stretch, then one more recent sample.
+            sample(HvacMode::Heating, 50_000),
+        ];
+        assert!(!is_hunting(&samples, 5_000, 1));
+    }
+
+    #[test]
+    fn stats_count_each_mode() {
+        let samples = vec![
+            sample(HvacMode::Heating, 0),
+            sample(HvacMode::Heating, 1000),
+            sample(HvacMode::Idle, 2000),
+            sample(HvacMode::Cooling, 3000),
+        ];
+        let stats = compute_stats(&samples);
+        assert_eq!(stats.total_samples, 4);
+        assert_eq!(stats.heating_samples, 2);
+        assert_eq!(stats.idle_samples, 1);
+        assert_eq!(stats.cooling_samples, 1);
+    }
+
+    #[test]
+    fn segment_widths_split_proportionally() {
+        let stats = HvacStats {
+            total_samples: 4,
+            heating_samples: 2,
+            cooling_samples: 1,
+            idle_samples: 1,
+            hunting: false,
+        };
+        assert_eq!(stats.segment_widths(100), (50, 25, 25));
+    }
+
+    #[test]
+    fn sample_period_secs_defaults_to_the_global_constant() {
+        let timeline = HvacTimeline::new();
+        assert_eq!(timeline.sample_period_secs(), SAMPLE_PERIOD_SECS);
+    }
+
+    #[test]
+    fn a_custom_history_period_wraps_and_computes_stats_correctly() {
+        let mut timeline = HvacTimeline::new();
+        timeline.set_sample_period_secs(30);
+        assert_eq!(timeline.sample_period_secs(), 30);
+
+        // Push more than TIMELINE_CAPACITY samples to exercise wrap-around.
+        for i in 0..(TIMELINE_CAPACITY + 10) {
+            let mode = if i % 2 == 0 { HvacMode::Heating } else { HvacMode::Idle };
+            timeline.push(sample(mode, i as u64 * 30_000));
+        }
+
+        let samples: Vec<HvacSample> = timeline.iter().copied().collect();
+        assert_eq!(samples.len(), TIMELINE_CAPACITY);
+
+        let stats = compute_stats(&samples);
+        assert_eq!(stats.total_samples, TIMELINE_CAPACITY);
+        assert_eq!(stats.heating_samples + stats.idle_samples, TIMELINE_CAPACITY);
+    }
+
+    #[test]
+    fn segment_widths_is_all_idle_with_no_samples() {
+        let stats = HvacStats {
+            total_samples: 0,
+            heating_samples: 0,
+            cooling_samples: 0,
+            idle_samples: 0,
+            hunting: false,
+        };
+        assert_eq!(stats.segment_widths(100), (0, 0, 100));
+    }
+}
@@ -0,0 +1,143 @@
+//! Shared I2C register read/write abstraction used by the ES8311 audio
+//! codec (the crate has no TCA9554 GPIO expander helpers), so bring-up
+//! logic (retries,
+//! timeouts) can be written once and unit-tested against a mock bus
+//! instead of needing real hardware.
+
+/// Clock speed and per-transaction timeout for an I2C bus. The ESP-IDF
+/// default of a fixed 100ms timeout doesn't suit every board (a slow bus
+/// or long wiring run may need longer), so this is threaded through every
+/// register helper rather than hard-coded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct I2cConfig {
+    pub clock_hz: u32,
+    pub timeout_ms: u32,
+}
+
+impl Default for I2cConfig {
+    fn default() -> Self {
+        Self {
+            clock_hz: 400_000,
+            timeout_ms: 100,
+        }
+    }
+}
+
+impl I2cConfig {
+    /// Returns a copy of this config with a different transaction timeout,
+    /// leaving the clock speed unchanged.
+    pub fn with_timeout(self, timeout_ms: u32) -> Self {
+        Self { timeout_ms, ..self }
+    }
+}
+
+/// A byte-addressed I2C register bus: exactly what the ES8311 codec's
+/// register helpers need, and small enough to mock.
+pub trait RegisterBus {
+    type Error;
+
+    fn write_reg(&mut self, addr: u8, reg: u8, value: u8, config: I2cConfig) -> Result<(), Self::Error>;
+    fn read_reg(&mut self, addr: u8, reg: u8, config: I2cConfig) -> Result<u8, Self::Error>;
+}
+
+#[cfg(test)]
+pub mod mock {
+    //! Host-side mock bus for testing register bring-up logic (retries,
+    //! timeout plumbing) without real hardware.
+
+    use super::{I2cConfig, RegisterBus};
+    use std::collections::VecDeque;
+
+    #[derive(Debug, PartialEq)]
+    pub enum Call {
+        Write { addr: u8, reg: u8, value: u8, timeout_ms: u32 },
+        Read { addr: u8, reg: u8, timeout_ms: u32 },
+    }
+
+    /// A mock bus whose responses are scripted in advance: each call pops
+    /// the next queued result, so a test can simulate "fails once then
+    /// succeeds" or "returns a specific chip ID".
+    #[derive(Default)]
+    pub struct MockI2cBus {
+        pub calls: Vec<Call>,
+        pub write_results: VecDeque<Result<(), &'static str>>,
+        pub read_results: VecDeque<Result<u8, &'static str>>,
+    }
+
+    impl MockI2cBus {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl RegisterBus for MockI2cBus {
+        type Error = &'static str;
+
+        fn write_reg(&mut self, addr: u8, reg: u8, value: u8, config: I2cConfig) -> Result<(), Self::Error> {
+            self.calls.push(Call::Write {
+                addr,
+                reg,
+                value,
+                timeout_ms: config.timeout_ms,
+            });
+            self.write_results.pop_front().unwrap_or(Ok(()))
+        }
+
+        fn read_reg(&mut self, addr: u8, reg: u8, config: I2cConfig) -> Result<u8, Self::Error> {
+            self.calls.push(Call::Read {
+                addr,
+                reg,
+                timeout_ms: config.timeout_ms,
+            });
+            self.read_results.pop_front().unwrap_or(Ok(0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::{Call, MockI2cBus};
+    use super::*;
+
+    #[test]
+    fn with_timeout_overrides_only_the_timeout() {
+        let config = I2cConfig::default().with_timeout(500);
+        assert_eq!(config.timeout_ms, 500);
+        assert_eq!(config.clock_hz, I2cConfig::default().clock_hz);
+    }
+
+    #[test]
+    fn write_reg_passes_the_configured_timeout_to_the_bus() {
+        let mut bus = MockI2cBus::new();
+        let config = I2cConfig::default().with_timeout(250);
+
+        bus.write_reg(0x18, 0x00, 0x01, config).unwrap();
+
+        assert_eq!(
+            bus.calls,
+            vec![Call::Write {
+                addr: 0x18,
+                reg: 0x00,
+                value: 0x01,
+                timeout_ms: 250,
+            }]
+        );
+    }
+
+    #[test]
+    fn read_reg_passes_the_configured_timeout_to_the_bus() {
+        let mut bus = MockI2cBus::new();
+        let config = I2cConfig::default().with_timeout(75);
+
+        bus.read_reg(0x18, 0x01, config).unwrap();
+
+        assert_eq!(
+            bus.calls,
+            vec![Call::Read {
+                addr: 0x18,
+                reg: 0x01,
+                timeout_ms: 75,
+            }]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Cheap pre-parse sanity scan shared by every endpoint that hands
+//! untrusted response bodies to `serde_json`: a size cap and a
+//! brace/bracket balance + nesting-depth check, both things `serde_json`
+//! would eventually catch too, but only after walking the whole (possibly
+//! huge or pathologically nested) structure. Braces inside quoted strings
+//! are ignored. Callers pick their own `max_body_bytes`/`max_depth` since
+//! a GeoJSON alert feed legitimately nests deeper and runs larger than a
+//! compact OWM current-conditions response.
+pub fn sanity_check_json(body: &str, max_body_bytes: usize, max_depth: u32) -> anyhow::Result<()> {
+    if body.len() > max_body_bytes {
+        anyhow::bail!("response body too large ({} bytes)", body.len());
+    }
+    if !body.trim_start().starts_with('{') {
+        anyhow::bail!("response body is not a JSON object");
+    }
+
+    let mut depth = 0u32;
+    let mut max_seen = 0u32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in body.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => {
+                depth += 1;
+                max_seen = max_seen.max(depth);
+                if max_seen > max_depth {
+                    anyhow::bail!("JSON nested past the {max_depth}-level limit");
+                }
+            }
+            '}' | ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        anyhow::bail!("response body has unbalanced braces/brackets");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced_json_passes_the_sanity_check() {
+        assert!(sanity_check_json(r#"{"main":{"temp":20.0}}"#, 1_024, 32).is_ok());
+    }
+
+    #[test]
+    fn unbalanced_json_is_rejected() {
+        assert!(sanity_check_json(r#"{"main":{"temp":20.0}"#, 1_024, 32).is_err());
+    }
+
+    #[test]
+    fn pathologically_nested_json_is_rejected() {
+        let extra_depth = 33;
+        let body = format!(r#"{{"main":{}{}}}"#, "{".repeat(extra_depth), "}".repeat(extra_depth));
+        assert!(sanity_check_json(&body, 1_024 * 16, 32).is_err());
+    }
+
+    #[test]
+    fn oversized_body_is_rejected() {
+        let body = format!(r#"{{"main":"{}"}}"#, "x".repeat(1_024));
+        assert!(sanity_check_json(&body, 256, 32).is_err());
+    }
+
+    #[test]
+    fn braces_inside_strings_do_not_count_toward_depth() {
+        let body = r#"{"headline":"contains { and [ characters"}"#;
+        assert!(sanity_check_json(body, 1_024, 2).is_ok());
+    }
+}
@@ -0,0 +1,123 @@
+//! Reassembles a JSON payload fed in one chunk at a time (e.g. a captured
+//! HTTP response pasted line-by-line over the serial console), so it can
+//! be handed to the same parser real fetches use once it's complete.
+
+/// Accumulates chunks until the buffered text is a single balanced JSON
+/// object, then hands the reassembled payload back and resets.
+#[derive(Debug, Default)]
+pub struct JsonReplayAccumulator {
+    buffer: String,
+}
+
+impl JsonReplayAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds one chunk. Returns the reassembled payload once the buffered
+    /// text forms a complete JSON object (braces balance back to zero),
+    /// consuming the accumulated state; otherwise keeps buffering and
+    /// returns `None`.
+    pub fn feed(&mut self, chunk: &str) -> Option<String> {
+        if !self.buffer.is_empty() {
+            self.buffer.push(' ');
+        }
+        self.buffer.push_str(chunk);
+        if is_complete_object(&self.buffer) {
+            Some(std::mem::take(&mut self.buffer))
+        } else {
+            None
+        }
+    }
+
+    /// Discards any partially-accumulated payload (e.g. after a malformed
+    /// replay is abandoned).
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+/// Whether `s` is a syntactically balanced single JSON object: starts
+/// with `{` and its brace depth returns to zero, ignoring braces that
+/// appear inside quoted strings.
+fn is_complete_object(s: &str) -> bool {
+    if !s.trim_start().starts_with('{') {
+        return false;
+    }
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in s.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_chunk_complete_object_resolves_immediately() {
+        let mut acc = JsonReplayAccumulator::new();
+        assert_eq!(acc.feed(r#"{"main":{"temp":20.0}}"#), Some(r#"{"main":{"temp":20.0}}"#.to_string()));
+    }
+
+    #[test]
+    fn a_payload_split_across_several_chunks_reassembles() {
+        let mut acc = JsonReplayAccumulator::new();
+        assert_eq!(acc.feed(r#"{"main":{"#), None);
+        assert_eq!(acc.feed(r#""temp":20.0,"#), None);
+        let result = acc.feed(r#""humidity":55}}"#);
+        assert_eq!(
+            result,
+            Some(r#"{"main":{ "temp":20.0, "humidity":55}}"#.to_string())
+        );
+        // Reassembled text parses as the original object even though the
+        // exact whitespace differs from the source.
+        let parsed: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(parsed["main"]["humidity"], 55);
+    }
+
+    #[test]
+    fn braces_inside_string_values_do_not_confuse_the_depth_count() {
+        let mut acc = JsonReplayAccumulator::new();
+        let result = acc.feed(r#"{"headline":"contains a } brace"}"#);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn the_buffer_resets_after_a_completed_payload() {
+        let mut acc = JsonReplayAccumulator::new();
+        acc.feed("{}");
+        assert_eq!(acc.feed("{}"), Some("{}".to_string()));
+    }
+
+    #[test]
+    fn reset_discards_a_partial_payload() {
+        let mut acc = JsonReplayAccumulator::new();
+        acc.feed("{\"a\":");
+        acc.reset();
+        assert_eq!(acc.feed("{}"), Some("{}".to_string()));
+    }
+}
@@ -0,0 +1,194 @@
+mod alerts;
+mod audio;
+mod carousel;
+mod clock;
+mod config;
+mod console;
+mod diagnostics;
+mod display;
+mod graph;
+mod hvac;
+mod i2c_bus;
+mod json_guard;
+mod json_replay;
+mod net;
+mod nvs;
+mod panic_log;
+mod polling;
+mod power;
+mod pressure;
+mod redraw;
+mod ring_buffer;
+mod sensors;
+mod settings;
+mod state;
+mod thresholds;
+mod time;
+mod touch;
+mod watchdog;
+mod weather;
+
+use clock::{Clock, SystemClock};
+use config::AppConfig;
+use state::AppState;
+
+fn main() -> anyhow::Result<()> {
+    esp_idf_svc::sys::link_patches();
+    esp_idf_svc::log::EspLogger::initialize_default();
+
+    let nvs_partition = esp_idf_svc::nvs::EspDefaultNvsPartition::take()?;
+    panic_log::install(nvs_partition.clone());
+    let mut cached_weather = None;
+    let mut saved_settings = None;
+    let mut nvs_store = match nvs::Store::new(nvs_partition) {
+        Ok(mut store) => {
+            if let Some(crash) = panic_log::take_last_crash(&mut store) {
+                log::warn!("previous boot crashed: {crash}");
+            }
+            cached_weather = weather::cache::load(&store);
+            saved_settings = Some(settings::SettingsBlob::load(&store));
+            Some(store)
+        }
+        Err(e) => {
+            log::warn!("failed to open NVS store: {e}");
+            None
+        }
+    };
+    if power::last_reset_was_brownout() {
+        log::warn!("previous reset was a brown-out");
+    }
+
+    let epoch = SystemClock::new();
+    let mut config = AppConfig::default();
+    if let Some(settings) = saved_settings {
+        config.settings = settings;
+    }
+    let mut state = AppState::new(config);
+    state.nvs_store = nvs_store.take();
+    // Show the last-known-good reading immediately on boot, rather than a
+    // blank weather page while waiting on the first real fetch; the usual
+    // `weather::is_stale` staleness badge covers the case where it's old.
+    if let Some((weather, fetched_at_ms)) = cached_weather {
+        state.weather = Some(weather);
+        state.last_weather_fetch_ms = fetched_at_ms;
+    }
+    if let Some((pressure_history, hvac_timeline)) = unsafe { power::rtc_memory::take_on_boot() } {
+        log::info!("restored history from RTC memory after deep-sleep wake");
+        state.pressure_history = pressure_history;
+        state.hvac_timeline = hvac_timeline;
+    }
+    let sntp = time::start_sync()?;
+    watchdog::register()?;
+    let mut last_tick_ms = epoch.now_ms();
+    // No explicit Wi-Fi connect step exists yet in this tree; skip straight
+    // to waiting on time sync once the board reaches the main loop.
+    state.boot_stage = display::views::boot::BootStage::SyncingTime;
+
+    loop {
+        let now = epoch.now_ms();
+        state.now_ms = now;
+
+        if state.boot_stage == display::views::boot::BootStage::SyncingTime && time::is_synced(&sntp) {
+            state.boot_stage = display::views::boot::BootStage::FetchingWeather;
+            state.needs_redraw = true;
+        }
+        if state.boot_stage == display::views::boot::BootStage::FetchingWeather && state.weather.is_some() {
+            state.boot_stage = display::views::boot::BootStage::Ready;
+            state.needs_redraw = true;
+        }
+        if state
+            .wifi_prewarm
+            .should_prewarm(state.wifi_rssi_dbm.is_some(), state.config.wifi_prewarm_enabled)
+        {
+            net::prewarm::prewarm_dns(weather::OWM_HOST);
+        }
+
+        if watchdog::is_stalled(now, last_tick_ms) {
+            log::warn!("main loop stalled for {}ms", now - last_tick_ms);
+        }
+        last_tick_ms = now;
+        watchdog::feed();
+
+        if state.pending_factory_reset {
+            if let Ok(mut store) = nvs::Store::new(esp_idf_svc::nvs::EspDefaultNvsPartition::take()?)
+            {
+                let _ = store.erase_all();
+            }
+            unsafe {
+                esp_idf_svc::sys::esp_restart();
+            }
+        }
+
+        if carousel::should_advance(
+            now,
+            state.last_page_change_ms,
+            state.last_interaction_ms,
+            state.carousel_interval_ms,
+            state.carousel_enabled,
+        ) {
+            state.current_page =
+                display::navigation::next_page(state.current_page, &state.config.enabled_pages);
+            state.last_page_change_ms = now;
+            state.needs_redraw = true;
+        }
+
+        if polling::due(now, state.last_weather_fetch_ms, polling::WEATHER_POLL_INTERVAL_MS) {
+            // Weather fetch happens here once a network client is wired
+            // up. Current conditions always come from `OwmProvider` (NWS has
+            // no current-conditions endpoint); `alert_source` only picks the
+            // alert provider below.
+            // A successful fetch should also call `weather::cache::save` so
+            // the next boot has a last-known-good reading to show.
+            state.last_weather_fetch_ms = now;
+            state.needs_redraw = true;
+        }
+        if polling::due(now, state.last_alert_poll_ms, polling::ALERT_POLL_INTERVAL_MS) {
+            // Alert fetch happens here, on its own faster cadence, through
+            // `weather::provider::provider_for(state.config.alert_source)`.
+            state.last_alert_poll_ms = now;
+            state.needs_redraw = true;
+        }
+
+        if let Some(bme) = &state.bme {
+            if let Some(sample) = state.hvac_detector.push(now, bme) {
+                state.hvac_timeline.push(sample);
+            }
+        }
+
+        let bme_candidate = state.bme.as_ref().map(|bme| pressure::sampler::Candidate {
+            pressure_hpa: bme.pressure_hpa,
+            fetched_at_ms: now,
+        });
+        let owm_candidate = state
+            .weather
+            .as_ref()
+            .and_then(|w| w.pressure_hpa)
+            .map(|pressure_hpa| pressure::sampler::Candidate {
+                pressure_hpa,
+                fetched_at_ms: state.last_weather_fetch_ms,
+            });
+        state.last_pressure_sample_ms = pressure::sampler::tick(
+            &mut state.pressure_history,
+            now,
+            state.last_pressure_sample_ms,
+            bme_candidate,
+            owm_candidate,
+            state.weather.as_ref().map(|w| w.temp_c),
+        );
+
+        if state.sleep_mode_enabled {
+            unsafe {
+                power::rtc_memory::save_before_sleep(state.pressure_history, state.hvac_timeline);
+            }
+            power::sleep::SleepSchedule::new(state.sleep_poll_interval_mins).enter();
+        }
+
+        // Poll sensors/network, redraw the active view. Fleshed out as
+        // peripheral drivers land; the throttle decision is already wired
+        // so a future framebuffer push only happens when it says to.
+        if state.redraw_throttle.should_redraw(now, state.needs_redraw) {
+            state.needs_redraw = false;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(16));
+    }
+}
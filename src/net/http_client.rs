@@ -0,0 +1,130 @@
+//! Shared HTTP defaults (User-Agent, extra default headers, timeout) for
+//! every outbound request, merged/applied so call sites like
+//! [`crate::net::ota`] don't each repeat the same boilerplate.
+
+use std::time::Duration;
+
+use esp_idf_svc::http::client::Configuration;
+
+/// Sent with every request unless overridden by a per-call header of the
+/// same name.
+pub const DEFAULT_USER_AGENT: &str = "weather-touch-screen/0.1 (ESP32-S3; Waveshare Touch LCD 3.5B)";
+
+/// Used unless a call site needs something different (a quick alert poll
+/// vs. a larger forecast fetch) via [`HttpClientConfig::timeout_ms`].
+pub const DEFAULT_TIMEOUT_MS: u32 = 10_000;
+
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    pub user_agent: String,
+    /// Headers sent with every request in addition to User-Agent (e.g. an
+    /// `Accept` header a particular API expects).
+    pub default_headers: Vec<(String, String)>,
+    /// How long a request may take before `esp_idf_svc`'s HTTP client
+    /// gives up. A slow forecast endpoint shouldn't stall a quick alert
+    /// poll, so this is per-config rather than one crate-wide constant.
+    pub timeout_ms: u32,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: Vec::new(),
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        }
+    }
+}
+
+impl HttpClientConfig {
+    /// Builds the `esp_idf_svc` HTTP client configuration this config
+    /// describes: the global CA store plus `crt_bundle_attach` for TLS
+    /// (every endpoint we talk to is HTTPS), and [`Self::timeout_ms`].
+    pub fn make_config(&self) -> Configuration {
+        Configuration {
+            use_global_ca_store: true,
+            crt_bundle_attach: Some(esp_idf_svc::sys::esp_crt_bundle_attach),
+            timeout: Some(Duration::from_millis(self.timeout_ms as u64)),
+            ..Default::default()
+        }
+    }
+
+    /// Merges `User-Agent` and `default_headers` with per-call
+    /// `extra_headers`. On a name collision (case-insensitive) the
+    /// per-call value wins, so a call site can still override the
+    /// default User-Agent for one request if it needs to.
+    pub fn merged_headers<'a>(
+        &'a self,
+        extra_headers: &[(&'a str, &'a str)],
+    ) -> Vec<(&'a str, &'a str)> {
+        let mut merged: Vec<(&str, &str)> = Vec::with_capacity(1 + self.default_headers.len() + extra_headers.len());
+        merged.push(("User-Agent", self.user_agent.as_str()));
+        for (k, v) in &self.default_headers {
+            merged.push((k.as_str(), v.as_str()));
+        }
+        for &(k, v) in extra_headers {
+            match merged.iter_mut().find(|(ek, _)| ek.eq_ignore_ascii_case(k)) {
+                Some(existing) => existing.1 = v,
+                None => merged.push((k, v)),
+            }
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_alone_include_just_the_user_agent() {
+        let config = HttpClientConfig::default();
+        assert_eq!(config.merged_headers(&[]), vec![("User-Agent", DEFAULT_USER_AGENT)]);
+    }
+
+    #[test]
+    fn a_non_colliding_per_call_header_is_appended() {
+        let config = HttpClientConfig::default();
+        let merged = config.merged_headers(&[("Accept", "application/geo+json")]);
+        assert_eq!(
+            merged,
+            vec![
+                ("User-Agent", DEFAULT_USER_AGENT),
+                ("Accept", "application/geo+json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_per_call_header_overrides_a_default_on_name_collision() {
+        let config = HttpClientConfig {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            default_headers: vec![("Accept".to_string(), "application/json".to_string())],
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+        };
+        let merged = config.merged_headers(&[("Accept", "application/geo+json")]);
+        assert_eq!(merged, vec![("User-Agent", DEFAULT_USER_AGENT), ("Accept", "application/geo+json")]);
+    }
+
+    #[test]
+    fn a_per_call_user_agent_overrides_the_default() {
+        let config = HttpClientConfig::default();
+        let merged = config.merged_headers(&[("User-Agent", "custom/1.0")]);
+        assert_eq!(merged, vec![("User-Agent", "custom/1.0")]);
+    }
+
+    #[test]
+    fn make_config_uses_the_provided_timeout() {
+        let config = HttpClientConfig {
+            timeout_ms: 5_000,
+            ..HttpClientConfig::default()
+        };
+        assert_eq!(config.make_config().timeout, Some(Duration::from_millis(5_000)));
+    }
+
+    #[test]
+    fn make_config_defaults_to_the_default_timeout() {
+        let config = HttpClientConfig::default();
+        assert_eq!(config.make_config().timeout, Some(Duration::from_millis(DEFAULT_TIMEOUT_MS as u64)));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod http_client;
+pub mod ota;
+pub mod prewarm;
+pub mod psram_buf;
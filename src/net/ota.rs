@@ -0,0 +1,131 @@
+//! Over-the-air firmware update: downloads a new image over HTTPS and
+//! writes it to the inactive OTA partition via `esp_idf_svc::ota`.
+
+use embedded_svc::http::client::Client as HttpClient;
+use embedded_svc::http::Method;
+use esp_idf_svc::http::client::EspHttpConnection;
+use esp_idf_svc::ota::EspOta;
+use std::io::Read;
+
+use super::http_client::HttpClientConfig;
+
+const DOWNLOAD_CHUNK: usize = 4096;
+
+/// Reads `reader` in [`DOWNLOAD_CHUNK`]-sized chunks until EOF, passing
+/// each chunk to `on_chunk` (the OTA write, in production) and the
+/// cumulative bytes read so far to `on_progress`, alongside
+/// `content_length` (`None` when the server didn't send one). Used by
+/// both [`update_from_url`] and any future large-download callers (e.g. a
+/// forecast fetch) that want a progress bar driven by the same loop.
+/// Returns the total bytes read.
+pub fn stream_with_progress(
+    reader: &mut impl Read,
+    content_length: Option<u64>,
+    mut on_chunk: impl FnMut(&[u8]) -> anyhow::Result<()>,
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> anyhow::Result<u64> {
+    let mut buf = [0u8; DOWNLOAD_CHUNK];
+    let mut total_read = 0u64;
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        on_chunk(&buf[..n])?;
+        total_read += n as u64;
+        on_progress(total_read, content_length);
+    }
+    Ok(total_read)
+}
+
+/// Downloads the firmware image at `url` and flashes it to the inactive OTA
+/// slot, then marks it for boot on next reset. Caller is responsible for
+/// rebooting once this returns `Ok`.
+pub fn update_from_url(url: &str) -> anyhow::Result<()> {
+    update_from_url_with_progress(url, |_bytes_read, _content_length| {})
+}
+
+/// Same as [`update_from_url`], but calls `on_progress(bytes_read,
+/// content_length)` after every chunk written, so a UI can show a
+/// download bar.
+pub fn update_from_url_with_progress(
+    url: &str,
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> anyhow::Result<()> {
+    let http_config = HttpClientConfig::default();
+    let connection = EspHttpConnection::new(&http_config.make_config())?;
+    let mut client = HttpClient::wrap(connection);
+    let headers = http_config.merged_headers(&[]);
+    let request = client.request(Method::Get, url, &headers)?;
+    let mut response = request.submit()?;
+    let content_length = response.header("Content-Length").and_then(|s| s.parse::<u64>().ok());
+
+    let mut ota = EspOta::new()?;
+    let mut update = ota.initiate_update()?;
+
+    stream_with_progress(
+        &mut response,
+        content_length,
+        |chunk| {
+            update.write(chunk)?;
+            Ok(())
+        },
+        &mut on_progress,
+    )?;
+
+    update.complete()?;
+    log::info!("OTA update from {url} complete; will boot new image on reset");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn cumulative_bytes_reported_match_the_total_read_across_chunks() {
+        let data = vec![7u8; DOWNLOAD_CHUNK * 2 + 500];
+        let mut reader = Cursor::new(data.clone());
+        let mut chunk_lens = Vec::new();
+        let mut progress_calls = Vec::new();
+
+        let total = stream_with_progress(
+            &mut reader,
+            Some(data.len() as u64),
+            |chunk| {
+                chunk_lens.push(chunk.len());
+                Ok(())
+            },
+            |bytes_read, content_length| progress_calls.push((bytes_read, content_length)),
+        )
+        .unwrap();
+
+        assert_eq!(total, data.len() as u64);
+        assert_eq!(chunk_lens, vec![DOWNLOAD_CHUNK, DOWNLOAD_CHUNK, 500]);
+        assert_eq!(progress_calls.last(), Some(&(data.len() as u64, Some(data.len() as u64))));
+        for pair in progress_calls.windows(2) {
+            assert!(pair[1].0 > pair[0].0, "progress should only move forward");
+        }
+    }
+
+    #[test]
+    fn an_unknown_content_length_is_reported_as_none_on_every_call() {
+        let data = vec![1u8; 10];
+        let mut reader = Cursor::new(data);
+
+        let mut saw_any = false;
+        stream_with_progress(
+            &mut reader,
+            None,
+            |_chunk| Ok(()),
+            |_bytes_read, content_length| {
+                saw_any = true;
+                assert_eq!(content_length, None);
+            },
+        )
+        .unwrap();
+
+        assert!(saw_any, "expected on_progress to be called at least once");
+    }
+}
@@ -0,0 +1,86 @@
+//! Decides exactly once per Wi-Fi connect event whether a pre-warm
+//! request (DNS resolution, or a lightweight request to the weather API
+//! host) should fire, so a cold TLS handshake after idle radio time
+//! doesn't stall the first real fetch. Opt-in via
+//! [`crate::config::AppConfig::wifi_prewarm_enabled`], since it spends a
+//! little extra radio time/battery right after connecting.
+//!
+//! There's no explicit Wi-Fi connect event in this tree yet (see the main
+//! loop's boot-stage comment), so [`WifiPrewarm::should_prewarm`] is
+//! driven by `state.wifi_rssi_dbm` going from `None` to `Some` instead —
+//! the same signal the status bar and diagnostics view already treat as
+//! "radio is up". [`prewarm_dns`] is the actual pre-warm action: a plain
+//! DNS lookup, since no HTTP client is constructed in the main loop yet
+//! either.
+
+use std::net::ToSocketAddrs;
+
+/// Resolves `host` on the HTTPS port and discards the result; the lookup
+/// itself is the point; a warm resolver cache shaves latency off the
+/// first real request after a cold connect. Logs but never fails the
+/// caller — a missed pre-warm shouldn't hold up anything else in the main
+/// loop.
+pub fn prewarm_dns(host: &str) {
+    match (host, 443u16).to_socket_addrs() {
+        Ok(mut addrs) => log::info!("pre-warmed DNS for {host}: {:?}", addrs.next()),
+        Err(e) => log::warn!("DNS pre-warm for {host} failed: {e}"),
+    }
+}
+
+/// Tracks whether the radio was connected as of the last check, to detect
+/// the exact tick a connection is freshly established (as opposed to
+/// remaining connected across repeated polls).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WifiPrewarm {
+    was_connected: bool,
+}
+
+impl WifiPrewarm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call once per Wi-Fi state check with whether the radio is
+    /// currently connected. Returns `true` exactly on the transition from
+    /// disconnected to connected while `enabled` is set — the one moment
+    /// a pre-warm request should be scheduled. Staying connected across
+    /// further calls never fires again until a disconnect/reconnect.
+    pub fn should_prewarm(&mut self, is_connected: bool, enabled: bool) -> bool {
+        let just_connected = is_connected && !self.was_connected;
+        self.was_connected = is_connected;
+        just_connected && enabled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_fresh_connection_schedules_one_prewarm() {
+        let mut prewarm = WifiPrewarm::new();
+        assert!(prewarm.should_prewarm(true, true));
+    }
+
+    #[test]
+    fn staying_connected_never_schedules_again() {
+        let mut prewarm = WifiPrewarm::new();
+        assert!(prewarm.should_prewarm(true, true));
+        assert!(!prewarm.should_prewarm(true, true));
+        assert!(!prewarm.should_prewarm(true, true));
+    }
+
+    #[test]
+    fn disabled_prewarm_never_fires_even_on_connect() {
+        let mut prewarm = WifiPrewarm::new();
+        assert!(!prewarm.should_prewarm(true, false));
+    }
+
+    #[test]
+    fn reconnecting_after_a_drop_schedules_again() {
+        let mut prewarm = WifiPrewarm::new();
+        assert!(prewarm.should_prewarm(true, true));
+        assert!(!prewarm.should_prewarm(false, true));
+        assert!(prewarm.should_prewarm(true, true));
+    }
+}
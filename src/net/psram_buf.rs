@@ -0,0 +1,258 @@
+//! Staging buffer for an HTTP response body, sized to comfortably hold a
+//! full OWM/NWS payload in one read. Normally backed by PSRAM so it
+//! doesn't compete with everything else for the small internal-SRAM heap;
+//! if PSRAM allocation fails (a flaky PSRAM chip, a cold-boot race before
+//! external RAM is initialized, ...) falls back to a smaller
+//! internal-SRAM buffer and logs a warning instead of bricking the app —
+//! local sensor readings still need to display even if a weather fetch
+//! has to come back smaller or retry.
+//!
+//! No fetch pipeline owns a `PsramBuf` yet in this tree (see the main
+//! loop's weather-polling comment) — the idle-release/reallocate
+//! lifecycle and [`PsramBuf::high_water_mark`] are real, tested building
+//! blocks waiting for that caller, not wired into anything live. Until
+//! then `state.psram_high_water_bytes` stays `None` rather than reporting
+//! a stub `0` through the `mem` console command.
+
+/// Preferred PSRAM-backed buffer size: comfortably holds a full OWM/NWS
+/// response in one read.
+pub const PSRAM_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// Fallback internal-SRAM buffer size, used only if PSRAM allocation
+/// fails. Small enough that a large response may need more than one
+/// chunked read, but the device still boots and shows local sensor data.
+pub const FALLBACK_RESPONSE_SIZE: usize = 8 * 1024;
+
+/// How long [`PsramBuf`] must sit unused before [`PsramBuf::release_if_idle`]
+/// will free its backing memory, so TLS and other PSRAM consumers get it
+/// back between fetches. Also guards against thrashing: a buffer just
+/// reallocated by [`PsramBuf::ensure_allocated`] won't be eligible for
+/// release again until it's been idle this long itself.
+pub const IDLE_RELEASE_MS: u64 = 5 * 60 * 1_000;
+
+/// Where a [`PsramBuf`]'s backing memory actually came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufSource {
+    Psram,
+    SramFallback,
+}
+
+/// Abstracts the PSRAM malloc call so [`PsramBuf::new`] can be driven by
+/// an injected failure in tests without real PSRAM hardware; production
+/// code passes [`EspPsramAllocator`].
+pub trait PsramAllocator {
+    /// Attempts to allocate `size` bytes of PSRAM-backed memory; `None`
+    /// on failure.
+    fn try_alloc_psram(&self, size: usize) -> Option<Vec<u8>>;
+}
+
+/// Allocator used on-device: checks free `MALLOC_CAP_SPIRAM` space (the
+/// same query as [`crate::diagnostics::HeapReport`]) and, if there is room,
+/// allocates through the ordinary global allocator; the buffer itself is
+/// not requested from a PSRAM-only heap. Goes through a free-space check rather than attempting and catching a
+/// failed `malloc`, since `esp_idf_svc` doesn't expose a capability-aware
+/// fallible allocator directly.
+pub struct EspPsramAllocator;
+
+impl PsramAllocator for EspPsramAllocator {
+    fn try_alloc_psram(&self, size: usize) -> Option<Vec<u8>> {
+        let free =
+            unsafe { esp_idf_svc::sys::heap_caps_get_free_size(esp_idf_svc::sys::MALLOC_CAP_SPIRAM) } as usize;
+        if free < size {
+            return None;
+        }
+        Some(vec![0u8; size])
+    }
+}
+
+/// The HTTP response staging buffer; see the module docs for why it
+/// prefers PSRAM and what happens when that's unavailable.
+pub struct PsramBuf {
+    bytes: Vec<u8>,
+    source: BufSource,
+    /// `now_ms` as of the last [`Self::touch`] (allocation or
+    /// reallocation counts as a touch), for [`Self::release_if_idle`].
+    last_used_ms: u64,
+    /// Set by [`Self::release_if_idle`], cleared by
+    /// [`Self::ensure_allocated`]. While `true`, `bytes` is empty and
+    /// [`Self::capacity`] is `0`.
+    released: bool,
+    /// Largest `bytes_read` ever passed to [`Self::record_fetch_len`], to
+    /// help right-size [`PSRAM_RESPONSE_SIZE`] from real-world responses
+    /// (see the `mem` console command).
+    high_water_mark: usize,
+}
+
+impl PsramBuf {
+    fn alloc(allocator: &impl PsramAllocator) -> (Vec<u8>, BufSource) {
+        match allocator.try_alloc_psram(PSRAM_RESPONSE_SIZE) {
+            Some(bytes) => (bytes, BufSource::Psram),
+            None => {
+                log::warn!(
+                    "PSRAM allocation failed, falling back to a {FALLBACK_RESPONSE_SIZE}-byte internal buffer"
+                );
+                (vec![0u8; FALLBACK_RESPONSE_SIZE], BufSource::SramFallback)
+            }
+        }
+    }
+
+    /// Tries [`PSRAM_RESPONSE_SIZE`] bytes of PSRAM via `allocator`; on
+    /// failure, logs a warning and falls back to
+    /// [`FALLBACK_RESPONSE_SIZE`] bytes of plain memory instead of
+    /// panicking.
+    pub fn new(allocator: &impl PsramAllocator, now_ms: u64) -> Self {
+        let (bytes, source) = Self::alloc(allocator);
+        Self {
+            bytes,
+            source,
+            last_used_ms: now_ms,
+            released: false,
+            high_water_mark: 0,
+        }
+    }
+
+    pub fn source(&self) -> BufSource {
+        self.source
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Call with the byte length of each successfully completed fetch;
+    /// updates [`Self::high_water_mark`] if it's a new largest.
+    pub fn record_fetch_len(&mut self, bytes_read: usize) {
+        self.high_water_mark = self.high_water_mark.max(bytes_read);
+    }
+
+    /// The largest fetch length seen so far via [`Self::record_fetch_len`].
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
+    /// Call whenever the buffer is actually used for a fetch, so
+    /// [`Self::release_if_idle`] measures idle time from the most recent
+    /// activity rather than from allocation.
+    pub fn touch(&mut self, now_ms: u64) {
+        self.last_used_ms = now_ms;
+    }
+
+    /// Frees the backing memory if it's sat unused for at least
+    /// `idle_ms`, so other PSRAM consumers (TLS handshakes in particular)
+    /// can use it between fetches. Returns whether it actually released —
+    /// `false` if it's already released or hasn't been idle long enough
+    /// yet.
+    pub fn release_if_idle(&mut self, now_ms: u64, idle_ms: u64) -> bool {
+        if self.released || now_ms.saturating_sub(self.last_used_ms) < idle_ms {
+            return false;
+        }
+        self.bytes = Vec::new();
+        self.released = true;
+        true
+    }
+
+    /// Reallocates the backing memory via the same PSRAM-then-fallback
+    /// path as [`Self::new`] if [`Self::release_if_idle`] previously freed
+    /// it, and marks the buffer used as of `now_ms`. A no-op beyond the
+    /// touch if it's already allocated.
+    pub fn ensure_allocated(&mut self, allocator: &impl PsramAllocator, now_ms: u64) {
+        if self.released {
+            let (bytes, source) = Self::alloc(allocator);
+            self.bytes = bytes;
+            self.source = source;
+            self.released = false;
+        }
+        self.touch(now_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct AlwaysSucceeds;
+    impl PsramAllocator for AlwaysSucceeds {
+        fn try_alloc_psram(&self, size: usize) -> Option<Vec<u8>> {
+            Some(vec![0u8; size])
+        }
+    }
+
+    struct AlwaysFails;
+    impl PsramAllocator for AlwaysFails {
+        fn try_alloc_psram(&self, _size: usize) -> Option<Vec<u8>> {
+            None
+        }
+    }
+
+    #[test]
+    fn a_successful_allocation_uses_the_full_psram_response_size() {
+        let buf = PsramBuf::new(&AlwaysSucceeds, 0);
+        assert_eq!(buf.source(), BufSource::Psram);
+        assert_eq!(buf.capacity(), PSRAM_RESPONSE_SIZE);
+    }
+
+    #[test]
+    fn a_failed_allocation_falls_back_to_the_smaller_internal_buffer() {
+        let buf = PsramBuf::new(&AlwaysFails, 0);
+        assert_eq!(buf.source(), BufSource::SramFallback);
+        assert_eq!(buf.capacity(), FALLBACK_RESPONSE_SIZE);
+    }
+
+    #[test]
+    fn staying_under_the_idle_threshold_keeps_the_buffer_allocated() {
+        let mut buf = PsramBuf::new(&AlwaysSucceeds, 0);
+        assert!(!buf.release_if_idle(IDLE_RELEASE_MS - 1, IDLE_RELEASE_MS));
+        assert!(!buf.is_released());
+        assert_eq!(buf.capacity(), PSRAM_RESPONSE_SIZE);
+    }
+
+    #[test]
+    fn clearing_the_idle_threshold_releases_the_buffer() {
+        let mut buf = PsramBuf::new(&AlwaysSucceeds, 0);
+        assert!(buf.release_if_idle(IDLE_RELEASE_MS, IDLE_RELEASE_MS));
+        assert!(buf.is_released());
+        assert_eq!(buf.capacity(), 0);
+    }
+
+    #[test]
+    fn an_already_released_buffer_does_not_release_again() {
+        let mut buf = PsramBuf::new(&AlwaysSucceeds, 0);
+        assert!(buf.release_if_idle(IDLE_RELEASE_MS, IDLE_RELEASE_MS));
+        assert!(!buf.release_if_idle(IDLE_RELEASE_MS * 10, IDLE_RELEASE_MS));
+    }
+
+    #[test]
+    fn ensure_allocated_reallocates_a_released_buffer_and_touches_it() {
+        let mut buf = PsramBuf::new(&AlwaysSucceeds, 0);
+        buf.release_if_idle(IDLE_RELEASE_MS, IDLE_RELEASE_MS);
+
+        buf.ensure_allocated(&AlwaysSucceeds, IDLE_RELEASE_MS + 1_000);
+
+        assert!(!buf.is_released());
+        assert_eq!(buf.capacity(), PSRAM_RESPONSE_SIZE);
+        // Freshly reallocated, so it's not idle yet relative to its own touch.
+        assert!(!buf.release_if_idle(IDLE_RELEASE_MS + 1_000 + IDLE_RELEASE_MS - 1, IDLE_RELEASE_MS));
+    }
+
+    #[test]
+    fn the_high_water_mark_only_ever_moves_upward() {
+        let mut buf = PsramBuf::new(&AlwaysSucceeds, 0);
+
+        buf.record_fetch_len(1_000);
+        assert_eq!(buf.high_water_mark(), 1_000);
+
+        buf.record_fetch_len(5_000);
+        assert_eq!(buf.high_water_mark(), 5_000);
+
+        buf.record_fetch_len(2_000);
+        assert_eq!(buf.high_water_mark(), 5_000);
+    }
+}
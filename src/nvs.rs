@@ -0,0 +1,55 @@
+//! Thin wrapper around the ESP-IDF NVS (non-volatile storage) namespace used
+//! to persist user settings across reboots.
+
+use esp_idf_svc::nvs::{EspDefaultNvsPartition, EspNvs, NvsDefault};
+
+const NAMESPACE: &str = "weather_app";
+
+pub struct Store {
+    nvs: EspNvs<NvsDefault>,
+}
+
+impl Store {
+    pub fn new(partition: EspDefaultNvsPartition) -> anyhow::Result<Self> {
+        let nvs = EspNvs::new(partition, NAMESPACE, true)?;
+        Ok(Self { nvs })
+    }
+
+    pub fn get_str(&self, key: &str, buf: &mut [u8]) -> anyhow::Result<Option<String>> {
+        let value = self.nvs.get_str(key, buf)?;
+        Ok(value.map(|s| s.to_string()))
+    }
+
+    pub fn set_str(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+        self.nvs.set_str(key, value)?;
+        Ok(())
+    }
+
+    /// Wipes every key in the namespace. Used by the factory-reset
+    /// command; the caller is expected to reboot afterwards so the app
+    /// re-initializes from defaults.
+    pub fn erase_all(&mut self) -> anyhow::Result<()> {
+        self.nvs.remove_all()?;
+        Ok(())
+    }
+}
+
+/// Abstracts the key/value operations [`crate::settings::SettingsBlob`]
+/// needs, so its load/save round trip can be exercised against an
+/// in-memory fake in host tests without real NVS hardware (`EspNvs` can't
+/// be constructed off-device); production code uses [`Store`], whose
+/// `get_str`/`set_str` below just delegate to the inherent methods above.
+pub trait KvStore {
+    fn get_str(&self, key: &str, buf: &mut [u8]) -> anyhow::Result<Option<String>>;
+    fn set_str(&mut self, key: &str, value: &str) -> anyhow::Result<()>;
+}
+
+impl KvStore for Store {
+    fn get_str(&self, key: &str, buf: &mut [u8]) -> anyhow::Result<Option<String>> {
+        Store::get_str(self, key, buf)
+    }
+
+    fn set_str(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+        Store::set_str(self, key, value)
+    }
+}
@@ -0,0 +1,39 @@
+//! Persists panic info to NVS so it survives the reboot a panic triggers,
+//! letting us log a post-mortem on the next boot.
+
+use crate::nvs;
+
+const KEY: &str = "panic_msg";
+
+/// Installs a panic hook that best-effort writes the panic message/location
+/// to NVS before the default hook aborts the process. Must not itself
+/// panic — NVS write failures are swallowed.
+pub fn install(partition: esp_idf_svc::nvs::EspDefaultNvsPartition) {
+    std::panic::set_hook(Box::new(move |info| {
+        let msg = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+        let record = format!("{location}: {msg}");
+
+        if let Ok(mut store) = nvs::Store::new(partition.clone()) {
+            let _ = store.set_str(KEY, &record);
+        }
+        log::error!("panic: {record}");
+    }));
+}
+
+/// Reads and clears any post-mortem record left by a previous crash. Call
+/// once at boot, after logging is initialized.
+pub fn take_last_crash(store: &mut nvs::Store) -> Option<String> {
+    let mut buf = [0u8; 256];
+    let record = store.get_str(KEY, &mut buf).ok().flatten()?;
+    let _ = store.set_str(KEY, "");
+    Some(record)
+}
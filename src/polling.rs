@@ -0,0 +1,30 @@
+//! Generic "is it time to poll again" logic, shared by the weather and
+//! alert pollers (which run on deliberately different cadences: alerts are
+//! time-sensitive and polled often, weather conditions change slowly).
+
+pub const WEATHER_POLL_INTERVAL_MS: u64 = 10 * 60 * 1_000;
+pub const ALERT_POLL_INTERVAL_MS: u64 = 60 * 1_000;
+
+pub fn due(now_ms: u64, last_poll_ms: u64, interval_ms: u64) -> bool {
+    now_ms.saturating_sub(last_poll_ms) >= interval_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_due_before_interval_elapses() {
+        assert!(!due(5_000, 0, 10_000));
+    }
+
+    #[test]
+    fn due_once_interval_elapses() {
+        assert!(due(10_000, 0, 10_000));
+    }
+
+    #[test]
+    fn alert_interval_is_shorter_than_weather_interval() {
+        assert!(ALERT_POLL_INTERVAL_MS < WEATHER_POLL_INTERVAL_MS);
+    }
+}
@@ -0,0 +1,56 @@
+//! Supply-voltage monitoring: tracks whether we rebooted due to a
+//! brown-out, and classifies the current supply rail reading so the status
+//! bar can warn before a brown-out actually happens.
+
+pub mod rtc_memory;
+pub mod sleep;
+
+use esp_idf_svc::sys::{esp_reset_reason, esp_reset_reason_t_ESP_RST_BROWNOUT};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupplyLevel {
+    Ok,
+    Low,
+    Critical,
+}
+
+/// Below this the rail is sagging but likely still usable.
+const LOW_THRESHOLD_V: f32 = 4.8;
+/// Below this a brown-out reset is imminent.
+const CRITICAL_THRESHOLD_V: f32 = 4.5;
+
+/// Whether the last reset was caused by a brown-out (useful to surface
+/// once at boot, alongside the panic post-mortem).
+pub fn last_reset_was_brownout() -> bool {
+    unsafe { esp_reset_reason() == esp_reset_reason_t_ESP_RST_BROWNOUT }
+}
+
+pub fn classify_supply(volts: f32) -> SupplyLevel {
+    if volts < CRITICAL_THRESHOLD_V {
+        SupplyLevel::Critical
+    } else if volts < LOW_THRESHOLD_V {
+        SupplyLevel::Low
+    } else {
+        SupplyLevel::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_rail_is_ok() {
+        assert_eq!(classify_supply(5.0), SupplyLevel::Ok);
+    }
+
+    #[test]
+    fn sagging_rail_is_low() {
+        assert_eq!(classify_supply(4.7), SupplyLevel::Low);
+    }
+
+    #[test]
+    fn near_brownout_is_critical() {
+        assert_eq!(classify_supply(4.4), SupplyLevel::Critical);
+    }
+}
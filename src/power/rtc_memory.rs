@@ -0,0 +1,277 @@
+//! Preserves `PressureHistory`/HVAC history across deep sleep by placing
+//! them in RTC slow memory (the `.rtc.data` section), which survives a
+//! deep-sleep reset unlike ordinary RAM, without the flash wear of an NVS
+//! write every sleep cycle.
+//!
+//! A marker word distinguishes a real wake-from-sleep (where the section's
+//! contents are the last thing we wrote) from a cold boot or power-on
+//! reset (where they're undefined garbage left over from whatever used
+//! that memory before).
+//!
+//! The full-resolution `PressureHistory`/`HvacTimeline` (144 and 288
+//! samples respectively) don't fit: each sample slot is a `RingBuffer`'s
+//! `Option<T>`, not a bare `T`, so the two structs together run well past
+//! the ESP32-S3's ~8KB of RTC slow memory before accounting for anything
+//! ESP-IDF itself places there (Wi-Fi/BT calibration, coexistence state,
+//! the deep-sleep stub's stack). So only a decimated subset is snapshotted
+//! here — plain fixed arrays at [`RTC_PRESSURE_CAPACITY`]/
+//! [`RTC_HVAC_CAPACITY`], a fraction of the live histories' resolution —
+//! and restored into fresh, full-capacity histories on wake. A coarser
+//! trend across the sleep gap beats either not fitting or losing history
+//! entirely.
+
+use crate::hvac::{HvacMode, HvacSample, HvacTimeline, TIMELINE_CAPACITY};
+use crate::pressure::{PressureHistory, HISTORY_CAPACITY};
+
+const VALID_MARKER: u32 = 0xC0FFEE42;
+
+/// How many of [`HISTORY_CAPACITY`]'s 10-minute pressure samples survive a
+/// sleep cycle: every 6th, i.e. hourly instead of every 10 minutes.
+const RTC_PRESSURE_CAPACITY: usize = HISTORY_CAPACITY / 6;
+
+/// How many of [`TIMELINE_CAPACITY`]'s 5-minute HVAC samples survive a
+/// sleep cycle: every 6th, i.e. every 30 minutes instead of every 5.
+const RTC_HVAC_CAPACITY: usize = TIMELINE_CAPACITY / 6;
+
+/// Plain, fixed-array stand-in for [`PressureHistory`] sized to actually
+/// fit in RTC memory (see the module docs). Built by decimating a live
+/// `PressureHistory` in [`snapshot_pressure`], read back by
+/// [`restore_pressure`].
+#[derive(Clone, Copy)]
+struct RtcPressureSnapshot {
+    len: usize,
+    pressure_hpa: [f32; RTC_PRESSURE_CAPACITY],
+    outdoor_temp_c: [f32; RTC_PRESSURE_CAPACITY],
+    /// Whether `outdoor_temp_c[i]` is a real reading, since a plain `f32`
+    /// array has no slot for `PressureHistory`'s `Option<f32>`.
+    has_outdoor_temp: [bool; RTC_PRESSURE_CAPACITY],
+    sample_at_ms: [u64; RTC_PRESSURE_CAPACITY],
+}
+
+impl RtcPressureSnapshot {
+    const fn empty() -> Self {
+        Self {
+            len: 0,
+            pressure_hpa: [0.0; RTC_PRESSURE_CAPACITY],
+            outdoor_temp_c: [0.0; RTC_PRESSURE_CAPACITY],
+            has_outdoor_temp: [false; RTC_PRESSURE_CAPACITY],
+            sample_at_ms: [0; RTC_PRESSURE_CAPACITY],
+        }
+    }
+}
+
+/// Plain, fixed-array stand-in for [`HvacTimeline`]; see
+/// [`RtcPressureSnapshot`].
+#[derive(Clone, Copy)]
+struct RtcHvacSnapshot {
+    len: usize,
+    mode: [HvacMode; RTC_HVAC_CAPACITY],
+    timestamp_ms: [u64; RTC_HVAC_CAPACITY],
+    sample_period_secs: u64,
+}
+
+impl RtcHvacSnapshot {
+    const fn empty() -> Self {
+        Self {
+            len: 0,
+            mode: [HvacMode::Idle; RTC_HVAC_CAPACITY],
+            timestamp_ms: [0; RTC_HVAC_CAPACITY],
+            sample_period_secs: crate::hvac::SAMPLE_PERIOD_SECS,
+        }
+    }
+}
+
+/// Conservative ceiling for everything this module places in RTC slow
+/// memory, leaving headroom for ESP-IDF's own use of the same ~8KB region
+/// (Wi-Fi/BT calibration data, coexistence state, the deep-sleep stub's
+/// stack). Checked at compile time below so a future field addition that
+/// blows this budget fails the build instead of silently overflowing at
+/// link/flash time.
+const RTC_DATA_BUDGET_BYTES: usize = 4 * 1024;
+
+const _: () = assert!(
+    core::mem::size_of::<u32>()
+        + core::mem::size_of::<RtcPressureSnapshot>()
+        + core::mem::size_of::<RtcHvacSnapshot>()
+        <= RTC_DATA_BUDGET_BYTES,
+    "RTC slow-memory snapshot no longer fits its budget; shrink RTC_PRESSURE_CAPACITY/RTC_HVAC_CAPACITY \
+     or raise RTC_DATA_BUDGET_BYTES with care (it's shared with ESP-IDF's own RTC memory use)"
+);
+
+#[link_section = ".rtc.data"]
+static mut RTC_MARKER: u32 = 0;
+#[link_section = ".rtc.data"]
+static mut RTC_PRESSURE_HISTORY: RtcPressureSnapshot = RtcPressureSnapshot::empty();
+#[link_section = ".rtc.data"]
+static mut RTC_HVAC_TIMELINE: RtcHvacSnapshot = RtcHvacSnapshot::empty();
+
+/// Whether `marker` is the value we write just before sleeping, i.e.
+/// whether the RTC-memory history is trustworthy rather than leftover
+/// garbage from a cold boot.
+pub fn is_valid_wake(marker: u32) -> bool {
+    marker == VALID_MARKER
+}
+
+/// Decimates `history` down to [`RTC_PRESSURE_CAPACITY`] samples, keeping
+/// every `HISTORY_CAPACITY / RTC_PRESSURE_CAPACITY`-th one in order.
+fn snapshot_pressure(history: &PressureHistory) -> RtcPressureSnapshot {
+    let stride = (HISTORY_CAPACITY / RTC_PRESSURE_CAPACITY).max(1);
+    let mut snap = RtcPressureSnapshot::empty();
+    let samples = history
+        .values()
+        .zip(history.outdoor_temp_values())
+        .zip(history.sample_timestamps());
+    for (i, ((pressure_hpa, outdoor_temp_c), sample_at_ms)) in samples.enumerate() {
+        if i % stride != 0 {
+            continue;
+        }
+        if snap.len >= RTC_PRESSURE_CAPACITY {
+            break;
+        }
+        snap.pressure_hpa[snap.len] = pressure_hpa;
+        if let Some(temp) = outdoor_temp_c {
+            snap.outdoor_temp_c[snap.len] = temp;
+            snap.has_outdoor_temp[snap.len] = true;
+        }
+        snap.sample_at_ms[snap.len] = sample_at_ms;
+        snap.len += 1;
+    }
+    snap
+}
+
+/// Rebuilds a full-capacity [`PressureHistory`] from a decimated
+/// snapshot; the gaps the decimation introduced just show up as wider
+/// spacing between points, same as a delayed sample would.
+fn restore_pressure(snap: &RtcPressureSnapshot) -> PressureHistory {
+    let mut history = PressureHistory::new();
+    for i in 0..snap.len {
+        let outdoor_temp_c = snap.has_outdoor_temp[i].then_some(snap.outdoor_temp_c[i]);
+        history.push(snap.pressure_hpa[i], outdoor_temp_c, snap.sample_at_ms[i]);
+    }
+    history
+}
+
+/// Decimates `timeline` down to [`RTC_HVAC_CAPACITY`] samples, same
+/// stride approach as [`snapshot_pressure`].
+fn snapshot_hvac(timeline: &HvacTimeline) -> RtcHvacSnapshot {
+    let stride = (TIMELINE_CAPACITY / RTC_HVAC_CAPACITY).max(1);
+    let mut snap = RtcHvacSnapshot::empty();
+    snap.sample_period_secs = timeline.sample_period_secs();
+    for (i, sample) in timeline.iter().enumerate() {
+        if i % stride != 0 {
+            continue;
+        }
+        if snap.len >= RTC_HVAC_CAPACITY {
+            break;
+        }
+        snap.mode[snap.len] = sample.mode;
+        snap.timestamp_ms[snap.len] = sample.timestamp_ms;
+        snap.len += 1;
+    }
+    snap
+}
+
+/// Rebuilds a full-capacity [`HvacTimeline`] from a decimated snapshot.
+fn restore_hvac(snap: &RtcHvacSnapshot) -> HvacTimeline {
+    let mut timeline = HvacTimeline::new();
+    timeline.set_sample_period_secs(snap.sample_period_secs);
+    for i in 0..snap.len {
+        timeline.push(HvacSample {
+            mode: snap.mode[i],
+            timestamp_ms: snap.timestamp_ms[i],
+        });
+    }
+    timeline
+}
+
+/// Reads history out of RTC memory on boot, if the marker says it's valid.
+/// Returns `None` on a cold boot, where the caller should start with fresh,
+/// empty history instead.
+///
+/// # Safety
+/// Must only be called once, early in `main`, before anything else
+/// accesses the RTC statics (no concurrent access, single-threaded boot).
+pub unsafe fn take_on_boot() -> Option<(PressureHistory, HvacTimeline)> {
+    if !is_valid_wake(RTC_MARKER) {
+        return None;
+    }
+    Some((restore_pressure(&RTC_PRESSURE_HISTORY), restore_hvac(&RTC_HVAC_TIMELINE)))
+}
+
+/// Writes history into RTC memory and sets the valid marker. Must be
+/// called right before entering deep sleep, since nothing else flushes it.
+///
+/// # Safety
+/// Must only be called immediately before `esp_deep_sleep`, with no
+/// concurrent access to the RTC statics.
+pub unsafe fn save_before_sleep(pressure: PressureHistory, hvac: HvacTimeline) {
+    RTC_PRESSURE_HISTORY = snapshot_pressure(&pressure);
+    RTC_HVAC_TIMELINE = snapshot_hvac(&hvac);
+    RTC_MARKER = VALID_MARKER;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marker_value_from_a_prior_save_is_valid() {
+        assert!(is_valid_wake(VALID_MARKER));
+    }
+
+    #[test]
+    fn zeroed_cold_boot_memory_is_not_valid() {
+        assert!(!is_valid_wake(0));
+    }
+
+    #[test]
+    fn arbitrary_garbage_is_not_mistaken_for_valid() {
+        assert!(!is_valid_wake(0xDEAD_BEEF));
+    }
+
+    #[test]
+    fn decimated_pressure_round_trip_keeps_every_strided_sample_in_order() {
+        let mut history = PressureHistory::new();
+        for i in 0..HISTORY_CAPACITY {
+            history.push(i as f32, Some(i as f32 * 2.0), i as u64 * 1_000);
+        }
+        let restored = restore_pressure(&snapshot_pressure(&history));
+        let stride = HISTORY_CAPACITY / RTC_PRESSURE_CAPACITY;
+        let expected: Vec<f32> = (0..HISTORY_CAPACITY).step_by(stride).map(|i| i as f32).collect();
+        assert_eq!(restored.values().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn a_missing_outdoor_reading_survives_the_round_trip_as_none() {
+        let mut history = PressureHistory::new();
+        history.push(1_000.0, None, 0);
+        let restored = restore_pressure(&snapshot_pressure(&history));
+        assert_eq!(restored.outdoor_temp_values().next(), Some(None));
+    }
+
+    #[test]
+    fn decimated_hvac_round_trip_keeps_every_strided_sample_in_order() {
+        let mut timeline = HvacTimeline::new();
+        for i in 0..TIMELINE_CAPACITY {
+            timeline.push(HvacSample {
+                mode: HvacMode::Heating,
+                timestamp_ms: i as u64 * 1_000,
+            });
+        }
+        let restored = restore_hvac(&snapshot_hvac(&timeline));
+        let stride = TIMELINE_CAPACITY / RTC_HVAC_CAPACITY;
+        let expected: Vec<u64> = (0..TIMELINE_CAPACITY).step_by(stride).map(|i| i as u64 * 1_000).collect();
+        assert_eq!(
+            restored.iter().map(|s| s.timestamp_ms).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn the_combined_rtc_snapshot_fits_a_conservative_budget() {
+        let total = core::mem::size_of::<u32>()
+            + core::mem::size_of::<RtcPressureSnapshot>()
+            + core::mem::size_of::<RtcHvacSnapshot>();
+        assert!(total <= RTC_DATA_BUDGET_BYTES, "RTC snapshot is {total} bytes");
+    }
+}
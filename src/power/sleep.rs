@@ -0,0 +1,64 @@
+//! Deep-sleep scheduling for battery scenarios: the chip deep-sleeps
+//! between weather polls and wakes on a timer to refresh and redraw.
+//! Only the timer wake source is armed, so a touch does not wake it, and
+//! nothing here powers the display down before sleeping.
+
+use esp_idf_svc::sys::{esp_deep_sleep, esp_sleep_enable_timer_wakeup};
+
+/// Below this, a poll interval would wake the device so often deep sleep
+/// stops saving meaningful power.
+const MIN_POLL_INTERVAL_MINS: u32 = 1;
+
+/// How often to wake and refresh while `sleepmode` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SleepSchedule {
+    poll_interval_mins: u32,
+}
+
+impl SleepSchedule {
+    pub fn new(poll_interval_mins: u32) -> Self {
+        Self {
+            poll_interval_mins: poll_interval_mins.max(MIN_POLL_INTERVAL_MINS),
+        }
+    }
+
+    pub fn poll_interval_mins(&self) -> u32 {
+        self.poll_interval_mins
+    }
+
+    /// How long to sleep before the next timer wake-up.
+    pub fn wake_interval_ms(&self) -> u64 {
+        self.poll_interval_mins as u64 * 60_000
+    }
+
+    fn wake_interval_us(&self) -> u64 {
+        self.wake_interval_ms() * 1_000
+    }
+
+    /// Arms the timer wake source and enters deep sleep. Does not return:
+    /// the chip resets and re-runs `main` on wake, same as a cold boot,
+    /// which is why history needs to survive in RTC memory or NVS rather
+    /// than relying on any in-RAM state still being there afterward.
+    pub fn enter(&self) -> ! {
+        unsafe {
+            esp_sleep_enable_timer_wakeup(self.wake_interval_us());
+            esp_deep_sleep(self.wake_interval_us());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wake_interval_scales_with_poll_minutes() {
+        assert_eq!(SleepSchedule::new(10).wake_interval_ms(), 10 * 60_000);
+    }
+
+    #[test]
+    fn poll_interval_is_clamped_to_the_minimum() {
+        let schedule = SleepSchedule::new(0);
+        assert_eq!(schedule.poll_interval_mins(), MIN_POLL_INTERVAL_MINS);
+    }
+}
@@ -0,0 +1,346 @@
+//! Barometric pressure: sea-level normalization and rolling history used by
+//! the pressure graph and trend arrow.
+
+use crate::graph;
+use crate::ring_buffer::RingBuffer;
+
+pub mod sampler;
+
+/// 24h of history at a 10-minute sample interval.
+pub const HISTORY_CAPACITY: usize = 144;
+
+/// Window size (in samples) for the optional moving-average smoothing
+/// (see [`PressureHistory::smoothed_downsampled_into`]).
+const SMOOTHING_WINDOW_SAMPLES: usize = 5;
+
+/// How many hPa of change over the trend window counts as rising/falling
+/// rather than steady. 1 hPa/3h is the classic "storm coming" threshold.
+const TREND_THRESHOLD_HPA: f32 = 1.0;
+/// How far back (in samples) to compare against for the trend arrow.
+const TREND_WINDOW_SAMPLES: usize = 18; // 3h at 10-minute samples
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+/// Rolling pressure history, sampled at a fixed cadence. Outdoor
+/// temperature rides along in a parallel ring at the same cadence/index,
+/// so the pressure view can overlay both without a separate timeline.
+#[derive(Default, Clone, Copy)]
+pub struct PressureHistory {
+    samples: RingBuffer<f32, HISTORY_CAPACITY>,
+    outdoor_temp_c: RingBuffer<Option<f32>, HISTORY_CAPACITY>,
+    /// When each sample was actually taken, so the graph can place points
+    /// by elapsed time rather than assuming an even cadence — a delayed
+    /// sample (Wi-Fi stall, sleep) shows up as a wider gap instead of
+    /// silently compressing into its neighbors.
+    sample_at_ms: RingBuffer<u64, HISTORY_CAPACITY>,
+}
+
+impl PressureHistory {
+    pub const fn new() -> Self {
+        Self {
+            samples: RingBuffer::new(),
+            outdoor_temp_c: RingBuffer::new(),
+            sample_at_ms: RingBuffer::new(),
+        }
+    }
+
+    /// Pushes one consolidated sample, taken at `at_ms`. `outdoor_temp_c`
+    /// is `None` when no OWM reading was available that cycle, so the
+    /// overlay line just skips that point rather than showing a wrong
+    /// value.
+    pub fn push(&mut self, hpa: f32, outdoor_temp_c: Option<f32>, at_ms: u64) {
+        self.samples.push(hpa);
+        self.outdoor_temp_c.push(outdoor_temp_c);
+        self.sample_at_ms.push(at_ms);
+    }
+
+    pub fn latest(&self) -> Option<f32> {
+        self.samples.iter().last().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Outdoor temperature samples aligned index-for-index with
+    /// [`Self::values`], `None` where no reading was available.
+    pub fn outdoor_temp_values(&self) -> impl Iterator<Item = Option<f32>> + '_ {
+        self.outdoor_temp_c.iter().copied()
+    }
+
+    /// When each sample in [`Self::values`] was actually taken, aligned
+    /// index-for-index.
+    pub fn sample_timestamps(&self) -> impl Iterator<Item = u64> + '_ {
+        self.sample_at_ms.iter().copied()
+    }
+
+    /// Values and timestamps decimated to at most `target_width` points
+    /// (see [`graph::downsample_indices`]), allocating fresh `Vec`s each
+    /// call. Prefer [`Self::downsampled_into`] on a hot path (redrawn every
+    /// frame); this is the convenience form for one-off callers.
+    pub fn downsampled(&self, target_width: usize) -> (Vec<f32>, Vec<u64>) {
+        let mut values_out = Vec::new();
+        let mut timestamps_out = Vec::new();
+        self.downsampled_into(target_width, &mut values_out, &mut timestamps_out);
+        (values_out, timestamps_out)
+    }
+
+    /// Same decimation as [`Self::downsampled`], but writing into
+    /// caller-owned buffers (cleared first) instead of allocating new
+    /// `Vec`s — meant to be called every frame against scratch buffers the
+    /// view keeps around, so drawing the pressure graph doesn't churn the
+    /// heap on an otherwise memory-constrained target.
+    pub fn downsampled_into(&self, target_width: usize, values_out: &mut Vec<f32>, timestamps_out: &mut Vec<u64>) {
+        values_out.clear();
+        timestamps_out.clear();
+        let values: heapless::Vec<f32, HISTORY_CAPACITY> = self.values().collect();
+        let timestamps: heapless::Vec<u64, HISTORY_CAPACITY> = self.sample_timestamps().collect();
+        let indices = graph::downsample_indices(&values, target_width);
+        values_out.extend(indices.iter().map(|&i| values[i]));
+        timestamps_out.extend(indices.iter().map(|&i| timestamps[i]));
+    }
+
+    /// Same as [`Self::downsampled_into`], but smooths the series with a
+    /// [`SMOOTHING_WINDOW_SAMPLES`]-wide moving average first, so the
+    /// plotted line follows the trend rather than every sample-to-sample
+    /// wobble. Indices are picked against the *smoothed* series, so the
+    /// min/max markers highlight smoothed extremes, not raw spikes.
+    pub fn smoothed_downsampled_into(&self, target_width: usize, values_out: &mut Vec<f32>, timestamps_out: &mut Vec<u64>) {
+        values_out.clear();
+        timestamps_out.clear();
+        let raw: heapless::Vec<f32, HISTORY_CAPACITY> = self.values().collect();
+        let smoothed = graph::moving_average(&raw, SMOOTHING_WINDOW_SAMPLES);
+        let timestamps: heapless::Vec<u64, HISTORY_CAPACITY> = self.sample_timestamps().collect();
+        let indices = graph::downsample_indices(&smoothed, target_width);
+        values_out.extend(indices.iter().map(|&i| smoothed[i]));
+        timestamps_out.extend(indices.iter().map(|&i| timestamps[i]));
+    }
+
+    /// Mean, standard deviation, and min/max of the recorded pressure
+    /// samples (taken from the BME280), for a volatility hint in the
+    /// pressure view (e.g. "sigma 2.1 hPa"). `None` until there are at
+    /// least two samples.
+    pub fn bme_stats(&self) -> Option<SeriesStats> {
+        series_stats(self.values())
+    }
+
+    /// Rising/falling/steady based on the change over the trend window, or
+    /// `Steady` if we don't have enough history yet.
+    pub fn trend(&self) -> Trend {
+        let values: heapless::Vec<f32, HISTORY_CAPACITY> = self.samples.iter().copied().collect();
+        if values.len() <= TREND_WINDOW_SAMPLES {
+            return Trend::Steady;
+        }
+        let latest = values[values.len() - 1];
+        let past = values[values.len() - 1 - TREND_WINDOW_SAMPLES];
+        let delta = latest - past;
+        if delta >= TREND_THRESHOLD_HPA {
+            Trend::Rising
+        } else if delta <= -TREND_THRESHOLD_HPA {
+            Trend::Falling
+        } else {
+            Trend::Steady
+        }
+    }
+}
+
+/// Mean, sample standard deviation, and min/max of one series, for
+/// characterizing volatility (e.g. the pressure view's "sigma 2.1 hPa"
+/// hint). `None` for fewer than two finite values (a std-dev needs at
+/// least two samples to mean anything).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    pub mean: f32,
+    pub std_dev: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Computes [`SeriesStats`] over `values` in one pass, skipping
+/// non-finite entries (`NaN`/`inf`) rather than letting them poison the
+/// mean.
+fn series_stats(values: impl Iterator<Item = f32>) -> Option<SeriesStats> {
+    let finite: heapless::Vec<f32, HISTORY_CAPACITY> = values.filter(|v| v.is_finite()).collect();
+    if finite.len() < 2 {
+        return None;
+    }
+    let n = finite.len() as f32;
+    let mean = finite.iter().sum::<f32>() / n;
+    let variance = finite.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / (n - 1.0);
+    let min = finite.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = finite.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    Some(SeriesStats {
+        mean,
+        std_dev: variance.sqrt(),
+        min,
+        max,
+    })
+}
+
+/// Converts a station-level pressure reading to sea-level-equivalent
+/// pressure using the standard barometric formula, so readings are
+/// comparable across altitudes (and to OWM's sea-level-normalized values).
+pub fn sea_level_hpa(station_hpa: f32, altitude_m: f32, temp_c: f32) -> f32 {
+    let temp_k = temp_c + 273.15;
+    station_hpa * (1.0 - (0.0065 * altitude_m) / (temp_k + 0.0065 * altitude_m)).powf(-5.257)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f32, b: f32, tol: f32) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn sea_level_station_is_unchanged() {
+        let p = sea_level_hpa(1013.25, 0.0, 15.0);
+        assert!(approx_eq(p, 1013.25, 0.01), "got {p}");
+    }
+
+    #[test]
+    fn higher_altitude_normalizes_upward() {
+        // At ~300m, a 980 hPa station reading should normalize to roughly
+        // 1017 hPa at sea level.
+        let p = sea_level_hpa(980.0, 300.0, 15.0);
+        assert!(approx_eq(p, 1017.0, 3.0), "got {p}");
+    }
+
+    #[test]
+    fn trend_is_steady_without_enough_history() {
+        let mut h = PressureHistory::new();
+        h.push(1013.0, None, 0);
+        h.push(1013.0, None, 600_000);
+        assert_eq!(h.trend(), Trend::Steady);
+    }
+
+    #[test]
+    fn trend_detects_rising_pressure() {
+        let mut h = PressureHistory::new();
+        for i in 0..=TREND_WINDOW_SAMPLES {
+            h.push(1000.0 + i as f32 * 0.2, None, i as u64 * 600_000);
+        }
+        assert_eq!(h.trend(), Trend::Rising);
+    }
+
+    #[test]
+    fn trend_detects_falling_pressure() {
+        let mut h = PressureHistory::new();
+        for i in 0..=TREND_WINDOW_SAMPLES {
+            h.push(1020.0 - i as f32 * 0.2, None, i as u64 * 600_000);
+        }
+        assert_eq!(h.trend(), Trend::Falling);
+    }
+
+    #[test]
+    fn trend_is_steady_for_small_fluctuations() {
+        let mut h = PressureHistory::new();
+        for i in 0..=TREND_WINDOW_SAMPLES {
+            h.push(1013.0 + if i % 2 == 0 { 0.1 } else { -0.1 }, None, i as u64 * 600_000);
+        }
+        assert_eq!(h.trend(), Trend::Steady);
+    }
+
+    #[test]
+    fn the_buffer_filling_downsample_matches_the_allocating_one() {
+        let mut h = PressureHistory::new();
+        for i in 0..HISTORY_CAPACITY {
+            h.push(1000.0 + (i as f32 * 0.37).sin() * 4.0, None, i as u64 * 600_000);
+        }
+
+        let (expected_values, expected_timestamps) = h.downsampled(40);
+
+        let mut values_out = Vec::new();
+        let mut timestamps_out = Vec::new();
+        h.downsampled_into(40, &mut values_out, &mut timestamps_out);
+
+        assert_eq!(values_out, expected_values);
+        assert_eq!(timestamps_out, expected_timestamps);
+    }
+
+    #[test]
+    fn downsampled_into_reuses_the_buffer_instead_of_growing_it_unbounded() {
+        let mut h = PressureHistory::new();
+        for i in 0..HISTORY_CAPACITY {
+            h.push(1000.0, None, i as u64 * 600_000);
+        }
+
+        let mut values_out = vec![0.0; 5]; // stale data from a smaller prior frame
+        let mut timestamps_out = vec![0; 5];
+        h.downsampled_into(40, &mut values_out, &mut timestamps_out);
+
+        assert_eq!(values_out.len(), 40);
+        assert_eq!(timestamps_out.len(), 40);
+    }
+
+    #[test]
+    fn bme_stats_matches_a_hand_computed_mean_and_std_dev() {
+        // 2, 4, 4, 4, 5, 5, 7, 9: textbook sample with mean 5, std-dev ~2.138.
+        let mut h = PressureHistory::new();
+        for (i, &v) in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].iter().enumerate() {
+            h.push(v, None, i as u64 * 600_000);
+        }
+
+        let stats = h.bme_stats().unwrap();
+        assert!(approx_eq(stats.mean, 5.0, 0.001), "got {}", stats.mean);
+        assert!(approx_eq(stats.std_dev, 2.138, 0.01), "got {}", stats.std_dev);
+        assert_eq!(stats.min, 2.0);
+        assert_eq!(stats.max, 9.0);
+    }
+
+    #[test]
+    fn bme_stats_is_none_with_fewer_than_two_samples() {
+        let mut h = PressureHistory::new();
+        h.push(1013.0, None, 0);
+        assert_eq!(h.bme_stats(), None);
+    }
+
+    #[test]
+    fn bme_stats_skips_non_finite_samples() {
+        let mut h = PressureHistory::new();
+        h.push(1000.0, None, 0);
+        h.push(f32::NAN, None, 600_000);
+        h.push(1020.0, None, 1_200_000);
+
+        let stats = h.bme_stats().unwrap();
+        assert!(approx_eq(stats.mean, 1010.0, 0.001), "got {}", stats.mean);
+    }
+
+    #[test]
+    fn smoothed_downsampled_into_flattens_a_single_sample_spike() {
+        let mut h = PressureHistory::new();
+        for i in 0..20 {
+            let hpa = if i == 10 { 1030.0 } else { 1000.0 };
+            h.push(hpa, None, i as u64 * 600_000);
+        }
+
+        let mut values_out = Vec::new();
+        let mut timestamps_out = Vec::new();
+        h.smoothed_downsampled_into(20, &mut values_out, &mut timestamps_out);
+
+        assert!(values_out[10] < 1030.0, "spike should be smoothed down, got {}", values_out[10]);
+    }
+
+    #[test]
+    fn sample_timestamps_are_recorded_alongside_values() {
+        let mut h = PressureHistory::new();
+        h.push(1000.0, None, 0);
+        h.push(1001.0, None, 900_000); // a delayed sample, not exactly +600s
+        let timestamps: Vec<u64> = h.sample_timestamps().collect();
+        assert_eq!(timestamps, vec![0, 900_000]);
+    }
+}
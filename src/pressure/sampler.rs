@@ -0,0 +1,79 @@
+//! Consolidates BME280 and OWM pressure readings into one sample per
+//! period, so `PressureHistory` (and the pressure view) has a single
+//! source of truth instead of views reaching into `latest_bme`/`latest_owm`
+//! /`current_weather.pressure_hpa` directly.
+
+use super::PressureHistory;
+
+/// How often a consolidated sample is pushed into history.
+pub const SAMPLE_PERIOD_SECS: u64 = 600;
+
+/// One pressure reading from some source, tagged with when it was taken.
+#[derive(Debug, Clone, Copy)]
+pub struct Candidate {
+    pub pressure_hpa: f32,
+    pub fetched_at_ms: u64,
+}
+
+/// Picks the freshest (most recently fetched) candidate, skipping any with
+/// a non-positive pressure (see [`crate::weather`]'s zero/invalid
+/// handling).
+pub fn choose_freshest(candidates: &[Candidate]) -> Option<f32> {
+    candidates
+        .iter()
+        .filter(|c| c.pressure_hpa > 0.0)
+        .max_by_key(|c| c.fetched_at_ms)
+        .map(|c| c.pressure_hpa)
+}
+
+/// Runs one sampler tick: if `SAMPLE_PERIOD_SECS` has elapsed since
+/// `last_sample_ms`, consolidates `bme` and `owm` into one history sample
+/// and returns the new `last_sample_ms`. Otherwise returns `last_sample_ms`
+/// unchanged.
+pub fn tick(
+    history: &mut PressureHistory,
+    now_ms: u64,
+    last_sample_ms: u64,
+    bme: Option<Candidate>,
+    owm: Option<Candidate>,
+    outdoor_temp_c: Option<f32>,
+) -> u64 {
+    if now_ms.saturating_sub(last_sample_ms) < SAMPLE_PERIOD_SECS * 1_000 {
+        return last_sample_ms;
+    }
+    let candidates: Vec<Candidate> = [bme, owm].into_iter().flatten().collect();
+    if let Some(pressure) = choose_freshest(&candidates) {
+        history.push(pressure, outdoor_temp_c, now_ms);
+    }
+    now_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(pressure: f32, fetched_at_ms: u64) -> Candidate {
+        Candidate {
+            pressure_hpa: pressure,
+            fetched_at_ms,
+        }
+    }
+
+    #[test]
+    fn chooses_the_most_recently_fetched_candidate() {
+        let candidates = [candidate(1000.0, 100), candidate(1005.0, 500)];
+        assert_eq!(choose_freshest(&candidates), Some(1005.0));
+    }
+
+    #[test]
+    fn skips_invalid_zero_pressure_candidates() {
+        let candidates = [candidate(0.0, 900), candidate(998.0, 100)];
+        assert_eq!(choose_freshest(&candidates), Some(998.0));
+    }
+
+    #[test]
+    fn no_valid_candidates_returns_none() {
+        let candidates = [candidate(0.0, 100), candidate(-5.0, 200)];
+        assert_eq!(choose_freshest(&candidates), None);
+    }
+}
@@ -0,0 +1,80 @@
+//! Redraw throttling: decides whether a given main-loop tick should push a
+//! new frame to the panel, so the SPI bus isn't driven at full main-loop
+//! rate when nothing on screen has actually changed.
+
+/// Decides skip-vs-render given elapsed time and a view-supplied "dirty"
+/// flag. A tick always redraws if something marked the display dirty (a
+/// data update, a touch interaction, a page change); otherwise it redraws
+/// at most once per `min_interval_ms`, set from a configurable fps cap.
+pub struct RedrawThrottle {
+    min_interval_ms: u64,
+    last_redraw_ms: u64,
+}
+
+impl RedrawThrottle {
+    pub fn new(max_fps: u32) -> Self {
+        Self {
+            min_interval_ms: Self::min_interval_ms(max_fps),
+            last_redraw_ms: 0,
+        }
+    }
+
+    fn min_interval_ms(max_fps: u32) -> u64 {
+        if max_fps == 0 {
+            u64::MAX
+        } else {
+            1_000 / max_fps as u64
+        }
+    }
+
+    /// Overrides the fps cap (e.g. from a settings change).
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.min_interval_ms = Self::min_interval_ms(max_fps);
+    }
+
+    /// Returns whether this tick should redraw. If it does, `now_ms` is
+    /// remembered as the last redraw time so the next timed redraw is
+    /// measured from here.
+    pub fn should_redraw(&mut self, now_ms: u64, dirty: bool) -> bool {
+        let timed_redraw_due = now_ms.saturating_sub(self.last_redraw_ms) >= self.min_interval_ms;
+        if dirty || timed_redraw_due {
+            self.last_redraw_ms = now_ms;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redraws_immediately_when_dirty() {
+        let mut throttle = RedrawThrottle::new(30);
+        assert!(throttle.should_redraw(0, true));
+    }
+
+    #[test]
+    fn skips_a_clean_frame_before_the_interval_elapses() {
+        let mut throttle = RedrawThrottle::new(30); // ~33ms/frame
+        assert!(throttle.should_redraw(0, true));
+        assert!(!throttle.should_redraw(10, false));
+    }
+
+    #[test]
+    fn redraws_a_clean_frame_once_the_interval_elapses() {
+        let mut throttle = RedrawThrottle::new(30); // ~33ms/frame
+        assert!(throttle.should_redraw(0, true));
+        assert!(throttle.should_redraw(40, false));
+    }
+
+    #[test]
+    fn zero_fps_cap_disables_timed_redraws_entirely() {
+        let mut throttle = RedrawThrottle::new(0);
+        assert!(throttle.should_redraw(0, true));
+        assert!(!throttle.should_redraw(1_000_000, false));
+        assert!(throttle.should_redraw(1_000_000, true));
+    }
+}
@@ -0,0 +1,92 @@
+//! A fixed-capacity ring buffer, generic over the element type and
+//! capacity so it can back pressure history, HVAC runtime samples, or
+//! anything else that wants a rolling window without heap churn.
+
+#[derive(Clone, Copy)]
+pub struct RingBuffer<T, const N: usize> {
+    buf: [Option<T>; N],
+    /// Index of the oldest element.
+    head: usize,
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> RingBuffer<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [None; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Pushes a new element, evicting the oldest one once full.
+    pub fn push(&mut self, value: T) {
+        let write_idx = (self.head + self.len) % N;
+        self.buf[write_idx] = Some(value);
+        if self.len < N {
+            self.len += 1;
+        } else {
+            self.head = (self.head + 1) % N;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Iterates oldest-to-newest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).map(move |i| self.buf[(self.head + i) % N].as_ref().unwrap())
+    }
+}
+
+impl<T: Copy, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_buffer_has_no_elements() {
+        let rb: RingBuffer<i32, 4> = RingBuffer::new();
+        assert_eq!(rb.len(), 0);
+        assert!(rb.is_empty());
+        assert_eq!(rb.iter().collect::<Vec<_>>(), Vec::<&i32>::new());
+    }
+
+    #[test]
+    fn push_below_capacity_preserves_order() {
+        let mut rb: RingBuffer<i32, 4> = RingBuffer::new();
+        rb.push(1);
+        rb.push(2);
+        rb.push(3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(!rb.is_full());
+    }
+
+    #[test]
+    fn push_past_capacity_evicts_oldest() {
+        let mut rb: RingBuffer<i32, 3> = RingBuffer::new();
+        for v in 1..=5 {
+            rb.push(v);
+        }
+        assert!(rb.is_full());
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+}
@@ -0,0 +1,29 @@
+//! Local environmental sensors (as opposed to data fetched from the
+//! weather API).
+
+/// A single BME280 reading: local temperature/humidity/pressure.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BmeReading {
+    pub temp_c: f32,
+    pub humidity_pct: f32,
+    pub pressure_hpa: f32,
+}
+
+/// Last-known ok/fail status of each onboard sensor, for the status bar.
+/// Starts optimistic (`true`) so a slow first read doesn't flash red.
+#[derive(Debug, Clone, Copy)]
+pub struct SensorHealth {
+    pub bme_ok: bool,
+    pub imu_ok: bool,
+    pub touch_ok: bool,
+}
+
+impl Default for SensorHealth {
+    fn default() -> Self {
+        Self {
+            bme_ok: true,
+            imu_ok: true,
+            touch_ok: true,
+        }
+    }
+}
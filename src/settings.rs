@@ -0,0 +1,227 @@
+//! User-facing units and alert thresholds, persisted together as a single
+//! JSON blob in NVS (rather than one NVS key per field) so they stay
+//! consistent and round-trip in one write.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Units {
+    Metric,
+    Imperial,
+    /// Kelvin/mph, as OWM calls it. Not offered in the settings UI, but
+    /// accepted so the OWM `units` query param has a value for every
+    /// variant.
+    Standard,
+}
+
+impl Units {
+    /// The value OWM's `units` query parameter expects for this choice.
+    pub fn owm_param(self) -> &'static str {
+        match self {
+            Units::Metric => "metric",
+            Units::Imperial => "imperial",
+            Units::Standard => "standard",
+        }
+    }
+
+    /// Converts a temperature *slope* (a rate of change, e.g. °F/min)
+    /// entered in this unit to the internal °C/min the HVAC detector
+    /// always uses. Unlike converting an absolute temperature, no offset
+    /// applies — only Fahrenheit's 5/9 scale factor matters; Kelvin's
+    /// scale matches Celsius exactly.
+    pub fn slope_to_c_per_min(self, value_per_min: f32) -> f32 {
+        match self {
+            Units::Metric | Units::Standard => value_per_min,
+            Units::Imperial => value_per_min * 5.0 / 9.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Thresholds {
+    pub low_supply_v: f32,
+    pub critical_supply_v: f32,
+    pub hunting_max_transitions: usize,
+    /// HVAC heating/cooling slope threshold, always stored in °C/min
+    /// regardless of the user's display unit (see
+    /// [`Units::slope_to_c_per_min`] for entering it in °F/min).
+    pub hvac_slope_threshold_c_per_min: f32,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            low_supply_v: 4.8,
+            critical_supply_v: 4.5,
+            hunting_max_transitions: 4,
+            hvac_slope_threshold_c_per_min: 0.05,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBlob {
+    pub units: Units,
+    pub thresholds: Thresholds,
+    pub alert_audio: crate::alerts::AlertAudioSettings,
+    pub quiet_hours: crate::alerts::quiet_hours::QuietHours,
+    pub alert_display: crate::alerts::AlertDisplaySettings,
+    /// Floor below which an alert only shows as a status-bar badge instead
+    /// of raising the warning view or sounding a tone (see
+    /// [`crate::alerts::SeverityFilter`]).
+    pub severity_filter: crate::alerts::SeverityFilter,
+    /// Whether the pressure graph plots a moving average instead of the
+    /// raw samples (see [`crate::pressure::PressureHistory::smoothed_downsampled_into`]).
+    /// Persisted so the choice survives a reboot.
+    pub graph_smoothing_enabled: bool,
+}
+
+impl Default for SettingsBlob {
+    fn default() -> Self {
+        Self {
+            units: Units::Metric,
+            thresholds: Thresholds::default(),
+            alert_audio: crate::alerts::AlertAudioSettings::default(),
+            quiet_hours: crate::alerts::quiet_hours::QuietHours::default(),
+            alert_display: crate::alerts::AlertDisplaySettings::default(),
+            severity_filter: crate::alerts::SeverityFilter::default(),
+            graph_smoothing_enabled: false,
+        }
+    }
+}
+
+const NVS_KEY: &str = "settings";
+
+/// Read buffer for [`SettingsBlob::load`]. The serialized blob was ~539
+/// bytes as of the fields above (see `round_trips_through_a_kv_store`
+/// below) and only grows as settings are added; `EspNvs::get_str` returns
+/// an error rather than truncating when a value doesn't fit, so
+/// undersizing this buffer doesn't corrupt anything but silently falls
+/// back to defaults on every boot. Sized with headroom well past today's
+/// blob so the next few fields don't need another bump.
+const LOAD_BUF_BYTES: usize = 1024;
+
+impl SettingsBlob {
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn load(store: &impl crate::nvs::KvStore) -> Self {
+        let mut buf = [0u8; LOAD_BUF_BYTES];
+        store
+            .get_str(NVS_KEY, &mut buf)
+            .ok()
+            .flatten()
+            .and_then(|json| Self::from_json(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, store: &mut impl crate::nvs::KvStore) -> anyhow::Result<()> {
+        let json = self.to_json()?;
+        store.set_str(NVS_KEY, &json)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory [`crate::nvs::KvStore`] standing in for real NVS in host
+    /// tests. Mirrors `EspNvs::get_str`'s documented behavior of erroring
+    /// (rather than truncating) when the caller's buffer is too small, so
+    /// a test here would have caught the undersized [`LOAD_BUF_BYTES`]
+    /// that used to reset settings to defaults on every boot.
+    #[derive(Default)]
+    struct FakeStore {
+        values: std::collections::HashMap<String, String>,
+    }
+
+    impl crate::nvs::KvStore for FakeStore {
+        fn get_str(&self, key: &str, buf: &mut [u8]) -> anyhow::Result<Option<String>> {
+            let Some(value) = self.values.get(key) else {
+                return Ok(None);
+            };
+            if value.len() + 1 > buf.len() {
+                anyhow::bail!("buffer too small for stored value ({} bytes)", value.len());
+            }
+            Ok(Some(value.clone()))
+        }
+
+        fn set_str(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+            self.values.insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn round_trips_through_a_kv_store() {
+        let mut store = FakeStore::default();
+        let blob = SettingsBlob {
+            units: Units::Imperial,
+            graph_smoothing_enabled: true,
+            ..SettingsBlob::default()
+        };
+
+        blob.save(&mut store).unwrap();
+        let json = store.values.get(NVS_KEY).unwrap();
+        assert!(
+            json.len() < LOAD_BUF_BYTES,
+            "serialized settings ({} bytes) no longer fit LOAD_BUF_BYTES",
+            json.len()
+        );
+
+        let loaded = SettingsBlob::load(&store);
+        assert_eq!(loaded.units, Units::Imperial);
+        assert!(loaded.graph_smoothing_enabled);
+    }
+
+    #[test]
+    fn a_value_too_large_for_the_buffer_falls_back_to_defaults_instead_of_panicking() {
+        let mut store = FakeStore::default();
+        store.values.insert(NVS_KEY.to_string(), "x".repeat(LOAD_BUF_BYTES));
+
+        let loaded = SettingsBlob::load(&store);
+        assert_eq!(loaded.units, SettingsBlob::default().units);
+    }
+
+    #[test]
+    fn fahrenheit_slope_converts_to_celsius_per_minute() {
+        // 0.09 F/min * 5/9 = 0.05 C/min.
+        let c_per_min = Units::Imperial.slope_to_c_per_min(0.09);
+        assert!((c_per_min - 0.05).abs() < 1e-4, "got {c_per_min}");
+    }
+
+    #[test]
+    fn metric_and_standard_slopes_pass_through_unchanged() {
+        assert_eq!(Units::Metric.slope_to_c_per_min(0.05), 0.05);
+        assert_eq!(Units::Standard.slope_to_c_per_min(0.05), 0.05);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let blob = SettingsBlob {
+            units: Units::Imperial,
+            thresholds: Thresholds {
+                low_supply_v: 4.9,
+                critical_supply_v: 4.6,
+                hunting_max_transitions: 3,
+                hvac_slope_threshold_c_per_min: 0.05,
+            },
+            alert_audio: crate::alerts::AlertAudioSettings::default(),
+            quiet_hours: crate::alerts::quiet_hours::QuietHours::default(),
+            alert_display: crate::alerts::AlertDisplaySettings::default(),
+            severity_filter: crate::alerts::SeverityFilter::default(),
+            graph_smoothing_enabled: true,
+        };
+        let json = blob.to_json().unwrap();
+        let back = SettingsBlob::from_json(&json).unwrap();
+        assert_eq!(back.units, Units::Imperial);
+        assert_eq!(back.thresholds.hunting_max_transitions, 3);
+        assert!(back.graph_smoothing_enabled);
+    }
+}
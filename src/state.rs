@@ -0,0 +1,170 @@
+use crate::alerts::Alert;
+use crate::config::AppConfig;
+use crate::display::page::Page;
+use crate::display::views::boot::BootStage;
+use crate::hvac::{HvacDetector, HvacTimeline};
+use crate::power::SupplyLevel;
+use crate::pressure::PressureHistory;
+use crate::redraw::RedrawThrottle;
+use crate::sensors::{BmeReading, SensorHealth};
+use crate::weather::Weather;
+
+/// Top-level mutable application state shared across the main loop and views.
+pub struct AppState {
+    pub config: AppConfig,
+    pub weather: Option<Weather>,
+    pub last_weather_fetch_ms: u64,
+    pub last_alert_poll_ms: u64,
+    /// Monotonic millis as of the start of the current loop iteration, from
+    /// [`crate::clock::Clock::now_ms`]; refreshed once per tick so every
+    /// consumer (HVAC detection, the pressure sampler, the carousel, the
+    /// redraw throttle, ...) agrees on the same "now" instead of each
+    /// reading the clock independently.
+    pub now_ms: u64,
+    pub bme: Option<BmeReading>,
+    pub current_page: Page,
+    pub carousel_enabled: bool,
+    pub carousel_interval_ms: u64,
+    pub last_interaction_ms: u64,
+    pub last_page_change_ms: u64,
+    pub sensor_health: SensorHealth,
+    pub wifi_rssi_dbm: Option<i8>,
+    pub unix_time_s: Option<i64>,
+    pub utc_offset_s: i32,
+    pub supply_level: SupplyLevel,
+    pub pressure_history: PressureHistory,
+    pub last_pressure_sample_ms: u64,
+    pub hvac_timeline: HvacTimeline,
+    pub hvac_detector: HvacDetector,
+    pub tester_state: crate::display::tester::TesterState,
+    pub pending_factory_reset: bool,
+    pub sleep_mode_enabled: bool,
+    pub sleep_poll_interval_mins: u32,
+    /// Currently active NWS/OWM alerts, most severe first. Empty until a
+    /// poller populates it (see `polling::ALERT_POLL_INTERVAL_MS`).
+    pub active_alerts: Vec<Alert>,
+    /// Set by anything that changes what's on screen (a data update, a
+    /// touch interaction, a page change); cleared once the next frame is
+    /// drawn. Starts `true` so the first loop iteration always draws.
+    pub needs_redraw: bool,
+    pub redraw_throttle: RedrawThrottle,
+    /// Tracks the startup splash's progress; stays at `Ready` for the rest
+    /// of the app's life once boot completes.
+    pub boot_stage: BootStage,
+    /// Toggled by a long-press gesture (see [`crate::touch::is_long_press`])
+    /// to show/hide the read-only diagnostics overlay.
+    pub showing_diagnostics: bool,
+    /// Most recent heap/PSRAM snapshot, refreshed periodically so the
+    /// diagnostics overlay doesn't call into `esp_idf_svc` directly.
+    pub last_heap_report: Option<crate::diagnostics::HeapReport>,
+    /// Message from the most recent failed HTTP request, if any. Set once
+    /// a generic HTTP client lands (see `net` module); `None` until then.
+    pub last_http_error: Option<String>,
+    /// Reassembles a weather JSON payload fed chunk-by-chunk via the
+    /// `replayweather` console command.
+    pub weather_replay: crate::json_replay::JsonReplayAccumulator,
+    /// Scratch buffers for the pressure graph's downsampled series,
+    /// reused every frame (see [`PressureHistory::downsampled_into`])
+    /// instead of allocating fresh `Vec`s on every redraw.
+    pub pressure_graph_values: Vec<f32>,
+    pub pressure_graph_timestamps: Vec<u64>,
+    /// Scratch buffer for the pressure view's "1013.2 hPa /\" readout (see
+    /// [`crate::display::layout::format_into`]), reused every frame
+    /// instead of a fresh `format!` allocation.
+    pub pressure_label_scratch: String,
+    /// Screen-space X of the most recent touch on the pressure graph,
+    /// `None` when nothing is being touched. Set by touch handling, read
+    /// by [`crate::display::views::pressure::draw`] to place the cursor
+    /// and value tooltip (see [`crate::graph::index_from_x`]).
+    pub graph_touch_x: Option<i32>,
+    /// Scratch buffer for the pressure graph's touch tooltip text, reused
+    /// every frame like [`Self::pressure_label_scratch`].
+    pub graph_tooltip_scratch: String,
+    /// `now_ms` at which the most recent new alert took the top spot, per
+    /// [`crate::alerts::AlertLifecycle::raised`]; `None` once the pulse has
+    /// nothing to animate. Read by
+    /// [`crate::display::views::warnings::draw`] to fade the background
+    /// toward an accent color for the first few seconds after a new alert
+    /// arrives.
+    pub alert_pulse_started_ms: Option<u64>,
+    /// Mirrors [`crate::net::psram_buf::PsramBuf::high_water_mark`] once a
+    /// fetch pipeline owns a `PsramBuf`; reported by the `mem` console
+    /// command to help right-size `PSRAM_RESPONSE_SIZE`. `None` rather
+    /// than `0` until then, since no fetch pipeline exists yet in this
+    /// tree to ever update it — a bare `0` would read as "measured and
+    /// found to be zero" instead of "never measured".
+    pub psram_high_water_bytes: Option<usize>,
+    /// Edge-detects a fresh Wi-Fi connection (see
+    /// [`crate::net::prewarm::WifiPrewarm`]) off [`Self::wifi_rssi_dbm`]
+    /// going from `None` to `Some`, so a DNS pre-warm fires at most once
+    /// per connect.
+    pub wifi_prewarm: crate::net::prewarm::WifiPrewarm,
+    /// Tap targets registered by [`crate::display::views::warnings::draw`]
+    /// on its most recent draw (currently just the silence button); a
+    /// future touch dispatcher hit-tests a tap point against this via
+    /// [`crate::display::views::warnings::handle_tap`] instead of the view
+    /// recomputing its own layout.
+    pub warnings_buttons: crate::touch::ButtonRegistry,
+    /// Whether the currently-displayed alert's tone has been manually
+    /// silenced (see [`crate::display::views::warnings::handle_tap`]);
+    /// consulted by whatever plays [`crate::alerts::tone::RepeatPlayer`]'s
+    /// schedule once a real tone-playing call site exists.
+    pub alert_silence: crate::alerts::AlertSilence,
+    /// NVS handle for persisting `config.settings` when a console command
+    /// changes it (see `console::handle_hvac_set`, `console::handle_graph`);
+    /// set by `main()` once NVS opens successfully. `None` if NVS failed to
+    /// initialize at boot, in which case settings changes last only until
+    /// reboot instead of failing the command outright.
+    pub nvs_store: Option<crate::nvs::Store>,
+}
+
+impl AppState {
+    pub fn new(config: AppConfig) -> Self {
+        let redraw_throttle = RedrawThrottle::new(config.max_redraw_fps);
+        Self {
+            config,
+            weather: None,
+            last_weather_fetch_ms: 0,
+            last_alert_poll_ms: 0,
+            now_ms: 0,
+            bme: None,
+            current_page: Page::Weather,
+            carousel_enabled: false,
+            carousel_interval_ms: 10_000,
+            last_interaction_ms: 0,
+            last_page_change_ms: 0,
+            sensor_health: SensorHealth::default(),
+            wifi_rssi_dbm: None,
+            unix_time_s: None,
+            utc_offset_s: 0,
+            supply_level: SupplyLevel::Ok,
+            pressure_history: PressureHistory::new(),
+            last_pressure_sample_ms: 0,
+            hvac_timeline: HvacTimeline::new(),
+            hvac_detector: HvacDetector::new(),
+            tester_state: crate::display::tester::TesterState::new(),
+            pending_factory_reset: false,
+            sleep_mode_enabled: false,
+            sleep_poll_interval_mins: 10,
+            active_alerts: Vec::new(),
+            needs_redraw: true,
+            redraw_throttle,
+            boot_stage: BootStage::default(),
+            showing_diagnostics: false,
+            last_heap_report: None,
+            last_http_error: None,
+            weather_replay: crate::json_replay::JsonReplayAccumulator::new(),
+            pressure_graph_values: Vec::with_capacity(crate::pressure::HISTORY_CAPACITY),
+            pressure_graph_timestamps: Vec::with_capacity(crate::pressure::HISTORY_CAPACITY),
+            pressure_label_scratch: String::new(),
+            graph_touch_x: None,
+            graph_tooltip_scratch: String::new(),
+            alert_pulse_started_ms: None,
+            psram_high_water_bytes: None,
+            wifi_prewarm: crate::net::prewarm::WifiPrewarm::new(),
+            warnings_buttons: crate::touch::ButtonRegistry::new(),
+            alert_silence: crate::alerts::AlertSilence::new(),
+            nvs_store: None,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Centralizes the "not enough history yet" gates used by the HVAC and
+//! pressure views. Each is expressed as a minimum number of *minutes* of
+//! history rather than a hard-coded sample count, so a view stops showing
+//! "collecting data" based on elapsed time regardless of how often its
+//! source actually samples.
+
+/// Minutes of HVAC timeline history required before the runtime-proportion
+/// bar and hunting check are considered meaningful.
+pub const HVAC_MIN_HISTORY_MINUTES: u32 = 10;
+
+/// Minutes of pressure history required before the trend arrow and graph
+/// are considered meaningful.
+pub const PRESSURE_MIN_HISTORY_MINUTES: u32 = 30;
+
+/// Converts a minimum-minutes requirement into a minimum sample count for a
+/// source sampled every `sample_period_secs`. Rounds up, so a requirement
+/// that doesn't divide evenly still demands at least that much history.
+pub fn min_samples_for_minutes(min_minutes: u32, sample_period_secs: u64) -> usize {
+    if sample_period_secs == 0 {
+        return 0;
+    }
+    let min_secs = min_minutes as u64 * 60;
+    min_secs.div_ceil(sample_period_secs) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gate_adapts_to_configured_cadence_not_a_fixed_count() {
+        // 10 minutes of history at a 5-minute cadence needs 2 samples...
+        assert_eq!(min_samples_for_minutes(10, 5 * 60), 2);
+        // ...but at a 1-minute cadence (a shortened sample period) it
+        // needs 10, not a hard-coded 2.
+        assert_eq!(min_samples_for_minutes(10, 60), 10);
+    }
+
+    #[test]
+    fn rounds_up_when_the_period_does_not_divide_evenly() {
+        assert_eq!(min_samples_for_minutes(10, 4 * 60), 3);
+    }
+
+    #[test]
+    fn zero_cadence_requires_nothing() {
+        assert_eq!(min_samples_for_minutes(10, 0), 0);
+    }
+}
@@ -0,0 +1,48 @@
+//! Wall-clock time: NTP sync via `esp_idf_svc::sntp` and formatting helpers
+//! for the status bar.
+
+use esp_idf_svc::sntp::{EspSntp, SyncStatus};
+
+/// Starts the SNTP client against the default pool. Keep the returned
+/// handle alive for the lifetime of the sync (dropping it stops syncing).
+pub fn start_sync() -> anyhow::Result<EspSntp<'static>> {
+    let sntp = EspSntp::new_default()?;
+    Ok(sntp)
+}
+
+pub fn is_synced(sntp: &EspSntp) -> bool {
+    sntp.get_sync_status() == SyncStatus::Completed
+}
+
+/// Formats a Unix timestamp (seconds) plus a local UTC offset (seconds,
+/// can be negative) as `HH:MM`, wrapping the day correctly in both
+/// directions.
+pub fn format_hh_mm(unix_time_s: i64, utc_offset_s: i32) -> String {
+    let local = unix_time_s + utc_offset_s as i64;
+    let secs_in_day = ((local % 86_400) + 86_400) % 86_400;
+    let hh = secs_in_day / 3600;
+    let mm = (secs_in_day % 3600) / 60;
+    format!("{hh:02}:{mm:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_midnight_utc() {
+        assert_eq!(format_hh_mm(0, 0), "00:00");
+    }
+
+    #[test]
+    fn formats_with_positive_offset() {
+        // 23:30 UTC + 2h -> 01:30 next day.
+        assert_eq!(format_hh_mm(23 * 3600 + 30 * 60, 2 * 3600), "01:30");
+    }
+
+    #[test]
+    fn formats_with_negative_offset_wrapping_back_a_day() {
+        // 00:30 UTC - 1h -> 23:30 previous day.
+        assert_eq!(format_hh_mm(30 * 60, -3600), "23:30");
+    }
+}
@@ -0,0 +1,92 @@
+//! A view's tap targets, decoupled from the input handler that interprets
+//! them: a view populates a [`ButtonRegistry`] with the rects it draws
+//! buttons into, then whatever handles the next touch event calls
+//! [`ButtonRegistry::hit_test`] instead of recomputing each button's
+//! layout inline. Keeps a screen with several buttons (pagination,
+//! toggles, a silence control) maintainable without layout and input
+//! logic drifting out of sync.
+
+use embedded_graphics::prelude::Point;
+use embedded_graphics::primitives::Rectangle;
+
+/// Identifies a registered button to whatever handles the hit-test
+/// result; views assign their own meaning (an enum discriminant, a page
+/// index, ...).
+pub type ButtonId = u16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Button {
+    pub rect: Rectangle,
+    pub id: ButtonId,
+}
+
+/// A view's tap targets for the frame it was just drawn. Cleared and
+/// repopulated every draw, since a button's rect (or whether it's shown
+/// at all) can change frame to frame.
+#[derive(Debug, Clone, Default)]
+pub struct ButtonRegistry {
+    buttons: Vec<Button>,
+}
+
+impl ButtonRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops any buttons registered on a previous draw; call before a view
+    /// starts registering this frame's.
+    pub fn clear(&mut self) {
+        self.buttons.clear();
+    }
+
+    pub fn register(&mut self, rect: Rectangle, id: ButtonId) {
+        self.buttons.push(Button { rect, id });
+    }
+
+    /// The id of the topmost registered button containing `point`, or
+    /// `None` if it falls outside all of them. Later registrations win
+    /// ties, matching draw order (later draws land on top).
+    pub fn hit_test(&self, point: Point) -> Option<ButtonId> {
+        self.buttons.iter().rev().find(|button| button.rect.contains(point)).map(|button| button.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn a_point_inside_a_registered_button_returns_its_id() {
+        let mut registry = ButtonRegistry::new();
+        registry.register(Rectangle::new(Point::new(10, 10), Size::new(40, 20)), 1);
+
+        assert_eq!(registry.hit_test(Point::new(20, 15)), Some(1));
+    }
+
+    #[test]
+    fn a_point_outside_every_button_returns_none() {
+        let mut registry = ButtonRegistry::new();
+        registry.register(Rectangle::new(Point::new(10, 10), Size::new(40, 20)), 1);
+
+        assert_eq!(registry.hit_test(Point::new(100, 100)), None);
+    }
+
+    #[test]
+    fn overlapping_buttons_resolve_to_the_later_registration() {
+        let mut registry = ButtonRegistry::new();
+        registry.register(Rectangle::new(Point::new(0, 0), Size::new(50, 50)), 1);
+        registry.register(Rectangle::new(Point::new(20, 20), Size::new(50, 50)), 2);
+
+        assert_eq!(registry.hit_test(Point::new(30, 30)), Some(2));
+    }
+
+    #[test]
+    fn clearing_drops_every_registered_button() {
+        let mut registry = ButtonRegistry::new();
+        registry.register(Rectangle::new(Point::new(10, 10), Size::new(40, 20)), 1);
+        registry.clear();
+
+        assert_eq!(registry.hit_test(Point::new(20, 15)), None);
+    }
+}
@@ -0,0 +1,142 @@
+//! Debouncing and edge detection for raw touch-controller reads, so a
+//! single physical tap doesn't register as several (contact bounce) or
+//! fire on every poll while held down.
+
+use super::RawPoint;
+
+/// A single debounced tap, with the point and press-down time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TapEvent {
+    pub point: RawPoint,
+    pub down_at_ms: u64,
+}
+
+/// Minimum time between two readings before a new contact is trusted,
+/// suppressing mechanical/electrical bounce right after touchdown.
+const DEBOUNCE_MS: u64 = 40;
+
+/// Tracks touch-down/touch-up edges across polls and debounces them.
+pub struct TouchDebouncer {
+    contact: bool,
+    /// `None` until the first edge is ever seen, so a genuine first
+    /// touchdown can't be mistaken for bounce off an edge that never
+    /// happened (see `first_touchdown_within_the_debounce_window_of_boot_still_fires`).
+    last_edge_ms: Option<u64>,
+}
+
+impl TouchDebouncer {
+    pub fn new() -> Self {
+        Self {
+            contact: false,
+            last_edge_ms: None,
+        }
+    }
+
+    /// Feeds one poll's result (`Some(point)` if the panel currently
+    /// reports contact, `None` otherwise) and returns a [`TapEvent`] only
+    /// on the debounced rising edge (touch-down after being up for at
+    /// least `DEBOUNCE_MS`).
+    pub fn poll(&mut self, now_ms: u64, raw: Option<RawPoint>) -> Option<TapEvent> {
+        match (self.contact, raw) {
+            (false, Some(point)) => {
+                let bounced = self
+                    .last_edge_ms
+                    .is_some_and(|last| now_ms.saturating_sub(last) < DEBOUNCE_MS);
+                if bounced {
+                    // Too soon after the last edge; treat as bounce, not a
+                    // new tap, but do record contact so touch-and-hold
+                    // still works.
+                    self.contact = true;
+                    None
+                } else {
+                    self.contact = true;
+                    self.last_edge_ms = Some(now_ms);
+                    Some(TapEvent {
+                        point,
+                        down_at_ms: now_ms,
+                    })
+                }
+            }
+            (true, None) => {
+                self.contact = false;
+                self.last_edge_ms = Some(now_ms);
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for TouchDebouncer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i32, y: i32) -> RawPoint {
+        RawPoint { x, y }
+    }
+
+    #[test]
+    fn first_touchdown_fires_immediately() {
+        let mut d = TouchDebouncer::new();
+        assert_eq!(
+            d.poll(100, Some(p(1, 1))),
+            Some(TapEvent {
+                point: p(1, 1),
+                down_at_ms: 100
+            })
+        );
+    }
+
+    #[test]
+    fn first_touchdown_within_the_debounce_window_of_boot_still_fires() {
+        // A touch at `now_ms < DEBOUNCE_MS` used to be swallowed as bounce
+        // against the old `last_edge_ms: 0` default, since nothing
+        // distinguished "no edge yet" from "an edge at time 0".
+        let mut d = TouchDebouncer::new();
+        assert_eq!(
+            d.poll(5, Some(p(1, 1))),
+            Some(TapEvent {
+                point: p(1, 1),
+                down_at_ms: 5
+            })
+        );
+    }
+
+    #[test]
+    fn held_contact_does_not_refire() {
+        let mut d = TouchDebouncer::new();
+        d.poll(100, Some(p(1, 1)));
+        assert_eq!(d.poll(110, Some(p(1, 1))), None);
+        assert_eq!(d.poll(500, Some(p(1, 1))), None);
+    }
+
+    #[test]
+    fn bounce_right_after_release_is_suppressed() {
+        let mut d = TouchDebouncer::new();
+        d.poll(0, Some(p(1, 1)));
+        d.poll(10, None); // release
+        // Bounces back within the debounce window.
+        assert_eq!(d.poll(20, Some(p(1, 1))), None);
+    }
+
+    #[test]
+    fn new_tap_after_debounce_window_fires() {
+        let mut d = TouchDebouncer::new();
+        d.poll(0, Some(p(1, 1)));
+        d.poll(10, None);
+        let tap = d.poll(1000, Some(p(2, 2)));
+        assert_eq!(
+            tap,
+            Some(TapEvent {
+                point: p(2, 2),
+                down_at_ms: 1000
+            })
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! CST816S touch controller: raw point mapping for each screen
+//! orientation, plus debounced tap detection.
+
+mod buttons;
+mod debounce;
+
+pub use buttons::{Button, ButtonId, ButtonRegistry};
+pub use debounce::{TapEvent, TouchDebouncer};
+
+/// How long a tap must be held before it counts as a long-press (used to
+/// gate the hidden diagnostics overlay, see
+/// [`crate::display::views::diagnostics`]).
+pub const LONG_PRESS_MS: u64 = 800;
+
+/// Whether a tap that went down at `down_at_ms` and is still being held at
+/// `now_ms` has been held long enough to count as a long-press.
+pub fn is_long_press(down_at_ms: u64, now_ms: u64) -> bool {
+    now_ms.saturating_sub(down_at_ms) >= LONG_PRESS_MS
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+    PortraitFlipped,
+    LandscapeFlipped,
+}
+
+/// A raw touch point, in the controller's native coordinate space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Maps a raw touch point into screen coordinates for the given display
+/// orientation and panel size. The CST816S reports points in the panel's
+/// native (portrait) coordinate space regardless of how the LCD content is
+/// rotated, so this has to counter-rotate to match.
+pub fn map_to_screen(raw: RawPoint, orientation: Orientation, width: u32, height: u32) -> RawPoint {
+    let w = width as i32;
+    let h = height as i32;
+    match orientation {
+        Orientation::Portrait => raw,
+        Orientation::Landscape => RawPoint {
+            x: raw.y,
+            y: h - 1 - raw.x,
+        },
+        Orientation::PortraitFlipped => RawPoint {
+            x: w - 1 - raw.x,
+            y: h - 1 - raw.y,
+        },
+        Orientation::LandscapeFlipped => RawPoint {
+            x: w - 1 - raw.y,
+            y: raw.x,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portrait_is_identity() {
+        let p = map_to_screen(RawPoint { x: 10, y: 20 }, Orientation::Portrait, 320, 480);
+        assert_eq!(p, RawPoint { x: 10, y: 20 });
+    }
+
+    #[test]
+    fn landscape_rotates_90_degrees() {
+        // Panel is 320x480 native; landscape screen is 480x320.
+        let p = map_to_screen(RawPoint { x: 0, y: 0 }, Orientation::Landscape, 480, 320);
+        assert_eq!(p, RawPoint { x: 0, y: 319 });
+    }
+
+    #[test]
+    fn flipped_portrait_mirrors_both_axes() {
+        let p = map_to_screen(RawPoint { x: 0, y: 0 }, Orientation::PortraitFlipped, 320, 480);
+        assert_eq!(p, RawPoint { x: 319, y: 479 });
+    }
+
+    #[test]
+    fn short_hold_is_not_a_long_press() {
+        assert!(!is_long_press(0, 500));
+    }
+
+    #[test]
+    fn hold_past_the_threshold_is_a_long_press() {
+        assert!(is_long_press(0, LONG_PRESS_MS));
+    }
+}
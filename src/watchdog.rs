@@ -0,0 +1,41 @@
+//! Task watchdog feeding and a simple stall detector for the main loop.
+
+use esp_idf_svc::sys::{esp, esp_task_wdt_add, esp_task_wdt_reset};
+
+/// How long the main loop can go between iterations before we consider it
+/// stalled (distinct from the hardware TWDT timeout, which would reset the
+/// board; this is for logging a warning before that happens).
+pub const STALL_THRESHOLD_MS: u64 = 2_000;
+
+/// Registers the current task with the task watchdog timer.
+pub fn register() -> anyhow::Result<()> {
+    esp!(unsafe { esp_task_wdt_add(std::ptr::null_mut()) })?;
+    Ok(())
+}
+
+/// Feeds the watchdog; call once per main-loop iteration.
+pub fn feed() {
+    unsafe {
+        esp_task_wdt_reset();
+    }
+}
+
+/// Whether the time since the last loop tick exceeds the stall threshold.
+pub fn is_stalled(now_ms: u64, last_tick_ms: u64) -> bool {
+    now_ms.saturating_sub(last_tick_ms) > STALL_THRESHOLD_MS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_stalled_within_threshold() {
+        assert!(!is_stalled(1_000, 0));
+    }
+
+    #[test]
+    fn stalled_past_threshold() {
+        assert!(is_stalled(STALL_THRESHOLD_MS + 1, 0));
+    }
+}
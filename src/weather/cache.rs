@@ -0,0 +1,82 @@
+//! Persists the last successfully parsed current-conditions to NVS, so a
+//! reboot mid-outage still has something to show (marked stale via the
+//! existing [`super::is_stale`] badge) instead of a blank weather page.
+
+use serde::{Deserialize, Serialize};
+
+use super::Weather;
+
+/// Bumped if `CachedWeather`'s shape changes; an unrecognized version is
+/// treated as a cache miss rather than failing to deserialize (and
+/// failing to boot).
+const CACHE_VERSION: u32 = 1;
+
+const NVS_KEY: &str = "last_weather";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedWeather {
+    version: u32,
+    weather: Weather,
+    fetched_at_ms: u64,
+}
+
+/// Saves `weather` (fetched at `fetched_at_ms`) as the last-known-good
+/// reading, overwriting any previous cache entry.
+pub fn save(store: &mut crate::nvs::Store, weather: &Weather, fetched_at_ms: u64) -> anyhow::Result<()> {
+    let cached = CachedWeather {
+        version: CACHE_VERSION,
+        weather: weather.clone(),
+        fetched_at_ms,
+    };
+    let json = serde_json::to_string(&cached)?;
+    store.set_str(NVS_KEY, &json)?;
+    Ok(())
+}
+
+/// Loads the last cached reading and the time it was fetched, if one
+/// exists and matches the current [`CACHE_VERSION`].
+pub fn load(store: &crate::nvs::Store) -> Option<(Weather, u64)> {
+    let mut buf = [0u8; 256];
+    let json = store.get_str(NVS_KEY, &mut buf).ok().flatten()?;
+    let cached: CachedWeather = serde_json::from_str(&json).ok()?;
+    if cached.version != CACHE_VERSION {
+        return None;
+    }
+    Some((cached.weather, cached.fetched_at_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json() {
+        let weather = Weather {
+            temp_c: 18.5,
+            feels_like_c: 17.0,
+            humidity_pct: Some(60.0),
+            pressure_hpa: Some(1012.0),
+            wind_speed_mps: Some(3.0),
+            wind_deg: Some(180.0),
+            wind_gust_mps: None,
+        };
+        let cached = CachedWeather {
+            version: CACHE_VERSION,
+            weather: weather.clone(),
+            fetched_at_ms: 123_456,
+        };
+        let json = serde_json::to_string(&cached).unwrap();
+        let back: CachedWeather = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.version, CACHE_VERSION);
+        assert_eq!(back.fetched_at_ms, 123_456);
+        assert_eq!(back.weather.temp_c, weather.temp_c);
+        assert_eq!(back.weather.pressure_hpa, weather.pressure_hpa);
+    }
+
+    #[test]
+    fn a_mismatched_version_is_treated_as_a_cache_miss_not_a_parse_error() {
+        let json = r#"{"version":999,"weather":{"temp_c":1.0,"feels_like_c":1.0,"humidity_pct":null,"pressure_hpa":null,"wind_speed_mps":null,"wind_deg":null,"wind_gust_mps":null},"fetched_at_ms":0}"#;
+        let cached: CachedWeather = serde_json::from_str(json).unwrap();
+        assert_ne!(cached.version, CACHE_VERSION);
+    }
+}
@@ -0,0 +1,49 @@
+//! Derived comfort metrics computed from local sensor readings (as opposed
+//! to values OWM gives us directly, like `feels_like`).
+
+/// Dew point in Celsius from temperature and relative humidity, via the
+/// Magnus-Tetens approximation. `rh_pct` is clamped to (0, 100] to avoid
+/// `ln(0)` at zero humidity.
+pub fn dew_point_c(temp_c: f32, rh_pct: f32) -> f32 {
+    const A: f32 = 17.62;
+    const B: f32 = 243.12;
+
+    let rh = rh_pct.clamp(0.1, 100.0);
+    let gamma = (A * temp_c) / (B + temp_c) + (rh / 100.0).ln();
+    (B * gamma) / (A - gamma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f32, b: f32, tol: f32) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn reference_value_20c_50pct() {
+        // Known reference: 20C / 50% RH -> dew point ~9.3C.
+        let dp = dew_point_c(20.0, 50.0);
+        assert!(approx_eq(dp, 9.3, 0.2), "got {dp}");
+    }
+
+    #[test]
+    fn reference_value_30c_80pct() {
+        // Known reference: 30C / 80% RH -> dew point ~26.2C.
+        let dp = dew_point_c(30.0, 80.0);
+        assert!(approx_eq(dp, 26.2, 0.3), "got {dp}");
+    }
+
+    #[test]
+    fn very_low_humidity_stays_well_below_temp() {
+        let dp = dew_point_c(25.0, 1.0);
+        assert!(dp < 0.0, "expected a deeply negative dew point, got {dp}");
+    }
+
+    #[test]
+    fn saturated_air_dew_point_equals_temp() {
+        let dp = dew_point_c(15.0, 100.0);
+        assert!(approx_eq(dp, 15.0, 0.05), "got {dp}");
+    }
+}
@@ -0,0 +1,52 @@
+//! Geometry for the wind-direction compass rose: rotating an arrow around a
+//! center point given a bearing in degrees (0 = north / up, clockwise).
+
+/// A simple float point, kept independent of `embedded_graphics::Point`
+/// (which is integer-only) so the trig stays exact until the final round to
+/// pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Computes the far endpoint of an arrow of the given `length` pinned at
+/// `center`, pointing along `bearing_deg` (compass bearing: 0° is up/north,
+/// 90° is right/east, increasing clockwise).
+pub fn arrow_endpoint(center: Point2, length: f32, bearing_deg: f32) -> Point2 {
+    let theta = bearing_deg.to_radians();
+    Point2 {
+        x: center.x + length * theta.sin(),
+        y: center.y - length * theta.cos(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn bearing_0_points_up() {
+        let p = arrow_endpoint(Point2 { x: 50.0, y: 50.0 }, 20.0, 0.0);
+        assert!(approx_eq(p.x, 50.0));
+        assert!(approx_eq(p.y, 30.0));
+    }
+
+    #[test]
+    fn bearing_90_points_right() {
+        let p = arrow_endpoint(Point2 { x: 50.0, y: 50.0 }, 20.0, 90.0);
+        assert!(approx_eq(p.x, 70.0));
+        assert!(approx_eq(p.y, 50.0));
+    }
+
+    #[test]
+    fn bearing_180_points_down() {
+        let p = arrow_endpoint(Point2 { x: 50.0, y: 50.0 }, 20.0, 180.0);
+        assert!(approx_eq(p.x, 50.0));
+        assert!(approx_eq(p.y, 70.0));
+    }
+}
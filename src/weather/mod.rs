@@ -0,0 +1,198 @@
+//! Current-conditions weather data: the OpenWeatherMap response shape and
+//! small derived-value helpers used by the weather view.
+
+mod compass;
+pub mod cache;
+pub mod comfort;
+pub mod provider;
+
+pub use compass::{arrow_endpoint, Point2};
+
+use serde::{Deserialize, Serialize};
+
+use crate::settings::Units;
+
+/// The weather API host — always OWM regardless of
+/// [`crate::config::AlertSource`], since NWS only ever supplies alerts
+/// (see [`crate::alerts::nws`]), not current conditions. Exposed so
+/// [`crate::net::prewarm`] can pre-warm DNS for it right after Wi-Fi
+/// connects.
+pub const OWM_HOST: &str = "api.openweathermap.org";
+
+const OWM_CURRENT_WEATHER_URL: &str = "https://api.openweathermap.org/data/2.5/weather";
+
+/// Builds the OWM `/weather` request URL, asking OWM to do the unit
+/// conversion server-side (`units=metric|imperial|standard`) rather than
+/// converting client-side after the fact. Pressure is unaffected by this
+/// choice — OWM always reports it in hPa regardless of `units` — so the
+/// pressure graph stays correct no matter which units the user picked.
+pub fn build_url(city_id: &str, api_key: &str, units: Units) -> String {
+    format!(
+        "{OWM_CURRENT_WEATHER_URL}?id={city_id}&appid={api_key}&units={}",
+        units.owm_param()
+    )
+}
+
+/// Current conditions, parsed down from the OWM `/weather` response into the
+/// fields the views actually need.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Weather {
+    #[serde(default)]
+    pub temp_c: f32,
+    #[serde(default)]
+    pub feels_like_c: f32,
+    /// `None` if OWM omitted `main.humidity`.
+    #[serde(default)]
+    pub humidity_pct: Option<f32>,
+    /// `None` if OWM omitted the field, or reported a zero/negative reading
+    /// from a partial parse — either way not a real pressure to chart.
+    #[serde(default)]
+    pub pressure_hpa: Option<f32>,
+    /// `None` if OWM omitted `wind.speed`.
+    #[serde(default)]
+    pub wind_speed_mps: Option<f32>,
+    /// `None` if OWM omitted `wind.deg` — there's no bearing to draw without
+    /// it, so the compass view skips drawing the arrow entirely.
+    #[serde(default)]
+    pub wind_deg: Option<f32>,
+    #[serde(default)]
+    pub wind_gust_mps: Option<f32>,
+}
+
+/// Rejects a response body larger than this before it's handed to serde,
+/// so a misbehaving/compromised endpoint can't force a huge allocation.
+const MAX_BODY_BYTES: usize = 16 * 1_024;
+
+/// Rejects JSON nested deeper than this. A legitimate OWM response nests
+/// only a few levels (`main`, `wind`, ...); anything past this is either
+/// corrupt or a pathological/adversarial payload designed to blow the
+/// stack during parsing.
+const MAX_JSON_DEPTH: u32 = 32;
+
+/// See [`crate::json_guard::sanity_check_json`]; bounds are OWM-sized.
+fn sanity_check_json(body: &str) -> anyhow::Result<()> {
+    crate::json_guard::sanity_check_json(body, MAX_BODY_BYTES, MAX_JSON_DEPTH)
+}
+
+/// Weather is considered stale (display a warning) past this age.
+pub const STALE_AFTER_MS: u64 = 2 * 60 * 60 * 1_000;
+
+/// Whether the last successful fetch is old enough to warn the user the
+/// on-screen conditions may no longer be accurate.
+pub fn is_stale(now_ms: u64, last_fetch_ms: u64, max_age_ms: u64) -> bool {
+    now_ms.saturating_sub(last_fetch_ms) > max_age_ms
+}
+
+impl Weather {
+    /// Parses the subset of fields we care about out of a raw OWM `/weather`
+    /// JSON body. Only `main.temp`/`main.feels_like` are treated as always
+    /// present (OWM never omits them); every other field is optional so a
+    /// partial response still yields usable current conditions instead of
+    /// failing the whole parse.
+    pub fn from_owm_json(body: &str) -> anyhow::Result<Self> {
+        sanity_check_json(body)?;
+        let v: serde_json::Value = serde_json::from_str(body)?;
+        let main = &v["main"];
+        let wind = &v["wind"];
+        Ok(Self {
+            temp_c: main["temp"].as_f64().unwrap_or(0.0) as f32,
+            feels_like_c: main["feels_like"].as_f64().unwrap_or(0.0) as f32,
+            humidity_pct: main["humidity"].as_f64().map(|h| h as f32),
+            pressure_hpa: main["pressure"].as_f64().filter(|p| *p > 0.0).map(|p| p as f32),
+            wind_speed_mps: wind["speed"].as_f64().map(|s| s as f32),
+            wind_deg: wind["deg"].as_f64().map(|d| d as f32),
+            wind_gust_mps: wind["gust"].as_f64().map(|g| g as f32),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_fetch_is_not_stale() {
+        assert!(!is_stale(1_000, 0, STALE_AFTER_MS));
+    }
+
+    #[test]
+    fn old_fetch_is_stale() {
+        assert!(is_stale(STALE_AFTER_MS + 1, 0, STALE_AFTER_MS));
+    }
+
+    #[test]
+    fn balanced_json_passes_the_sanity_check() {
+        assert!(sanity_check_json(r#"{"main":{"temp":20.0}}"#).is_ok());
+    }
+
+    #[test]
+    fn unbalanced_json_is_rejected() {
+        assert!(sanity_check_json(r#"{"main":{"temp":20.0}"#).is_err());
+    }
+
+    #[test]
+    fn pathologically_nested_json_is_rejected() {
+        let extra_depth = MAX_JSON_DEPTH as usize + 1;
+        let body = format!(
+            r#"{{"main":{}{}}}"#,
+            "{".repeat(extra_depth),
+            "}".repeat(extra_depth)
+        );
+        assert!(sanity_check_json(&body).is_err());
+    }
+
+    #[test]
+    fn oversized_body_is_rejected() {
+        let body = format!(r#"{{"main":"{}"}}"#, "x".repeat(MAX_BODY_BYTES));
+        assert!(sanity_check_json(&body).is_err());
+    }
+
+    #[test]
+    fn missing_pressure_yields_none_not_zero() {
+        let body = r#"{"main":{"temp":20.0}}"#;
+        let w = Weather::from_owm_json(body).unwrap();
+        assert_eq!(w.pressure_hpa, None);
+    }
+
+    #[test]
+    fn zero_pressure_yields_none() {
+        let body = r#"{"main":{"temp":20.0,"pressure":0}}"#;
+        let w = Weather::from_owm_json(body).unwrap();
+        assert_eq!(w.pressure_hpa, None);
+    }
+
+    #[test]
+    fn minimal_response_with_just_temp_still_parses() {
+        let body = r#"{"main":{"temp":18.5}}"#;
+        let w = Weather::from_owm_json(body).unwrap();
+        assert_eq!(w.temp_c, 18.5);
+        assert_eq!(w.humidity_pct, None);
+        assert_eq!(w.wind_speed_mps, None);
+        assert_eq!(w.wind_deg, None);
+    }
+
+    #[test]
+    fn full_response_parses_every_field() {
+        let body = r#"{
+            "main": {"temp": 18.5, "feels_like": 17.0, "humidity": 55, "pressure": 1012},
+            "wind": {"speed": 3.1, "deg": 270, "gust": 5.0}
+        }"#;
+        let w = Weather::from_owm_json(body).unwrap();
+        assert_eq!(w.temp_c, 18.5);
+        assert_eq!(w.feels_like_c, 17.0);
+        assert_eq!(w.humidity_pct, Some(55.0));
+        assert_eq!(w.pressure_hpa, Some(1012.0));
+        assert_eq!(w.wind_speed_mps, Some(3.1));
+        assert_eq!(w.wind_deg, Some(270.0));
+        assert_eq!(w.wind_gust_mps, Some(5.0));
+    }
+
+    #[test]
+    fn build_url_includes_units_param() {
+        let url = build_url("1234", "key", Units::Imperial);
+        assert_eq!(
+            url,
+            "https://api.openweathermap.org/data/2.5/weather?id=1234&appid=key&units=imperial"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Abstracts "where weather/alerts come from" behind a trait, so the
+//! fetch loop can call through it based on [`crate::config::AlertSource`]
+//! rather than hard-coding OWM's URLs/parse logic. [`OwmProvider`] is the
+//! default; [`NwsProvider`] covers the NWS alerts-only alternative (see
+//! [`crate::alerts::nws`]).
+
+use crate::alerts::Alert;
+use crate::settings::Units;
+
+use super::Weather;
+
+pub trait WeatherProvider {
+    /// Builds the current-conditions request URL, if this provider has a
+    /// current-conditions endpoint (NWS's alerts feed doesn't).
+    fn current_url(&self, city_id: &str, api_key: &str, units: Units) -> Option<String>;
+    /// Builds the active-alerts request URL, if this provider has one
+    /// (OWM's free tier doesn't provide a comparable endpoint).
+    fn alerts_url(&self, city_id: &str, api_key: &str) -> Option<String>;
+    fn parse_current(&self, body: &str) -> anyhow::Result<Weather>;
+    fn parse_alerts(&self, body: &str) -> anyhow::Result<Vec<Alert>>;
+}
+
+pub struct OwmProvider;
+
+impl WeatherProvider for OwmProvider {
+    fn current_url(&self, city_id: &str, api_key: &str, units: Units) -> Option<String> {
+        Some(super::build_url(city_id, api_key, units))
+    }
+
+    fn alerts_url(&self, _city_id: &str, _api_key: &str) -> Option<String> {
+        None
+    }
+
+    fn parse_current(&self, body: &str) -> anyhow::Result<Weather> {
+        Weather::from_owm_json(body)
+    }
+
+    fn parse_alerts(&self, _body: &str) -> anyhow::Result<Vec<Alert>> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct NwsProvider;
+
+impl WeatherProvider for NwsProvider {
+    fn current_url(&self, _city_id: &str, _api_key: &str, _units: Units) -> Option<String> {
+        None
+    }
+
+    fn alerts_url(&self, _city_id: &str, _api_key: &str) -> Option<String> {
+        Some(crate::alerts::nws::NWS_ALERTS_URL.to_string())
+    }
+
+    fn parse_current(&self, _body: &str) -> anyhow::Result<Weather> {
+        anyhow::bail!("NwsProvider has no current-conditions endpoint")
+    }
+
+    fn parse_alerts(&self, body: &str) -> anyhow::Result<Vec<Alert>> {
+        crate::alerts::nws::parse_active_alerts(body)
+    }
+}
+
+/// Picks the provider the fetch loop should use for alerts, per
+/// [`crate::config::AlertSource`]. OWM is also asked for current
+/// conditions regardless of this choice — NWS is alerts-only.
+pub fn provider_for(source: crate::config::AlertSource) -> Box<dyn WeatherProvider> {
+    match source {
+        crate::config::AlertSource::Owm => Box::new(OwmProvider),
+        crate::config::AlertSource::Nws => Box::new(NwsProvider),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockProvider {
+        canned_current_url: &'static str,
+        canned_alert: Alert,
+    }
+
+    impl WeatherProvider for MockProvider {
+        fn current_url(&self, _city_id: &str, _api_key: &str, _units: Units) -> Option<String> {
+            Some(self.canned_current_url.to_string())
+        }
+
+        fn alerts_url(&self, _city_id: &str, _api_key: &str) -> Option<String> {
+            Some("mock://alerts".to_string())
+        }
+
+        fn parse_current(&self, _body: &str) -> anyhow::Result<Weather> {
+            Ok(Weather {
+                temp_c: 21.0,
+                ..Weather::default()
+            })
+        }
+
+        fn parse_alerts(&self, _body: &str) -> anyhow::Result<Vec<Alert>> {
+            Ok(vec![self.canned_alert.clone()])
+        }
+    }
+
+    fn mock_alert() -> Alert {
+        crate::alerts::build_synthetic(crate::alerts::AlertKind::Watch, "Mock Watch", 0)
+    }
+
+    #[test]
+    fn a_fetch_loop_can_drive_purely_through_the_trait() {
+        let provider: Box<dyn WeatherProvider> = Box::new(MockProvider {
+            canned_current_url: "mock://current",
+            canned_alert: mock_alert(),
+        });
+
+        let url = provider.current_url("city", "key", Units::Metric).unwrap();
+        assert_eq!(url, "mock://current");
+        let weather = provider.parse_current("{}").unwrap();
+        assert_eq!(weather.temp_c, 21.0);
+
+        let alerts_url = provider.alerts_url("city", "key").unwrap();
+        assert_eq!(alerts_url, "mock://alerts");
+        let alerts = provider.parse_alerts("{}").unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].headline, "Mock Watch");
+    }
+
+    #[test]
+    fn owm_provider_has_no_alerts_endpoint() {
+        assert_eq!(OwmProvider.alerts_url("city", "key"), None);
+    }
+
+    #[test]
+    fn nws_provider_has_no_current_conditions_endpoint() {
+        assert_eq!(NwsProvider.current_url("city", "key", Units::Metric), None);
+    }
+
+    #[test]
+    fn provider_for_selects_by_alert_source() {
+        assert!(provider_for(crate::config::AlertSource::Nws)
+            .alerts_url("", "")
+            .is_some());
+        assert!(provider_for(crate::config::AlertSource::Owm)
+            .alerts_url("", "")
+            .is_none());
+    }
+}